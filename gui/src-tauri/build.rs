@@ -0,0 +1,10 @@
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    tonic_build::compile_protos("proto/federation.proto")?;
+
+    // Emits VERGEN_GIT_SHA (read by bench.rs's BenchmarkReport.git_commit) so benchmark runs can
+    // be compared across versions instead of every report saying "unknown".
+    vergen::EmitBuilder::builder().git_sha(false).emit()?;
+
+    tauri_build::build();
+    Ok(())
+}
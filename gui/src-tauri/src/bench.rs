@@ -0,0 +1,257 @@
+// Workload-driven benchmark runner for the evaluate/mutate pipeline.
+// Replays a JSON workload file against the Python backend and reports latency/score stats.
+
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::time::Instant;
+
+use crate::{AppState, EvaluateRequest, EvaluateResponse, MutateRequest, MutateResponse};
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Workload {
+    pub name: String,
+    #[serde(default = "default_warmup_runs")]
+    pub warmup_runs: u32,
+    #[serde(default = "default_runs")]
+    pub runs: u32,
+    pub steps: Vec<WorkloadStep>,
+}
+
+fn default_warmup_runs() -> u32 {
+    1
+}
+
+fn default_runs() -> u32 {
+    5
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct WorkloadStep {
+    pub op: StepOp,
+    pub payload: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum StepOp {
+    Evaluate,
+    Mutate,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StepStats {
+    pub op: StepOp,
+    pub runs: u32,
+    pub min_latency_ms: f64,
+    pub median_latency_ms: f64,
+    pub p95_latency_ms: f64,
+    pub max_latency_ms: f64,
+    pub mean_quality_score: f64,
+    pub mean_delta_score: f64,
+    pub cache_hit_rate: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchmarkReport {
+    pub workload_name: String,
+    pub git_commit: String,
+    pub started_at: f64,
+    pub step_results: Vec<StepStats>,
+}
+
+struct SampleSet {
+    round_trip_ms: Vec<f64>,
+    quality_scores: Vec<f64>,
+    delta_scores: Vec<f64>,
+    cache_hits: u32,
+    samples: u32,
+}
+
+impl SampleSet {
+    fn new() -> Self {
+        Self {
+            round_trip_ms: Vec::new(),
+            quality_scores: Vec::new(),
+            delta_scores: Vec::new(),
+            cache_hits: 0,
+            samples: 0,
+        }
+    }
+
+    fn stats(&self, op: StepOp) -> StepStats {
+        let mut sorted = self.round_trip_ms.clone();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let percentile = |p: f64| -> f64 {
+            if sorted.is_empty() {
+                return 0.0;
+            }
+            let idx = ((sorted.len() as f64 - 1.0) * p).round() as usize;
+            sorted[idx]
+        };
+
+        let mean = |xs: &[f64]| -> f64 {
+            if xs.is_empty() {
+                0.0
+            } else {
+                xs.iter().sum::<f64>() / xs.len() as f64
+            }
+        };
+
+        StepStats {
+            op,
+            runs: self.samples,
+            min_latency_ms: sorted.first().copied().unwrap_or(0.0),
+            median_latency_ms: percentile(0.5),
+            p95_latency_ms: percentile(0.95),
+            max_latency_ms: sorted.last().copied().unwrap_or(0.0),
+            mean_quality_score: mean(&self.quality_scores),
+            mean_delta_score: mean(&self.delta_scores),
+            cache_hit_rate: if self.samples == 0 {
+                0.0
+            } else {
+                self.cache_hits as f64 / self.samples as f64
+            },
+        }
+    }
+}
+
+async fn run_evaluate_step(
+    client: &reqwest::Client,
+    base_url: &str,
+    payload: &serde_json::Value,
+) -> Result<(f64, EvaluateResponse), String> {
+    let request: EvaluateRequest =
+        serde_json::from_value(payload.clone()).map_err(|e| format!("Invalid evaluate payload: {}", e))?;
+
+    let start = Instant::now();
+    let response = client
+        .post(format!("{}/evaluate", base_url))
+        .json(&request)
+        .timeout(std::time::Duration::from_secs(60))
+        .send()
+        .await
+        .map_err(|e| format!("Request failed: {}", e))?;
+
+    let body: EvaluateResponse = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse response: {}", e))?;
+    let round_trip_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+    Ok((round_trip_ms, body))
+}
+
+async fn run_mutate_step(
+    client: &reqwest::Client,
+    base_url: &str,
+    payload: &serde_json::Value,
+) -> Result<(f64, MutateResponse), String> {
+    let request: MutateRequest =
+        serde_json::from_value(payload.clone()).map_err(|e| format!("Invalid mutate payload: {}", e))?;
+
+    let start = Instant::now();
+    let response = client
+        .post(format!("{}/mutate", base_url))
+        .json(&request)
+        .timeout(std::time::Duration::from_secs(60))
+        .send()
+        .await
+        .map_err(|e| format!("Request failed: {}", e))?;
+
+    let body: MutateResponse = response
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse response: {}", e))?;
+    let round_trip_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+    Ok((round_trip_ms, body))
+}
+
+async fn run_step(
+    client: &reqwest::Client,
+    base_url: &str,
+    step: &WorkloadStep,
+    samples: &mut SampleSet,
+    discard: bool,
+) -> Result<(), String> {
+    match step.op {
+        StepOp::Evaluate => {
+            let (round_trip_ms, resp) = run_evaluate_step(client, base_url, &step.payload).await?;
+            if !discard {
+                samples.round_trip_ms.push(round_trip_ms);
+                samples.quality_scores.push(resp.quality_score);
+                samples.delta_scores.push(resp.delta_score);
+                samples.cache_hits += resp.cache_hit as u32;
+                samples.samples += 1;
+            }
+        }
+        StepOp::Mutate => {
+            let (round_trip_ms, resp) = run_mutate_step(client, base_url, &step.payload).await?;
+            if !discard {
+                samples.round_trip_ms.push(round_trip_ms);
+                samples.delta_scores.push(resp.delta_score);
+                samples.samples += 1;
+            }
+        }
+    }
+    Ok(())
+}
+
+pub async fn run_workload(
+    base_url: &str,
+    results_server_url: Option<&str>,
+    workload: Workload,
+) -> Result<BenchmarkReport, String> {
+    let client = reqwest::Client::new();
+    let mut step_results = Vec::with_capacity(workload.steps.len());
+
+    for step in &workload.steps {
+        let mut samples = SampleSet::new();
+
+        for _ in 0..workload.warmup_runs {
+            run_step(&client, base_url, step, &mut samples, true).await?;
+        }
+        for _ in 0..workload.runs {
+            run_step(&client, base_url, step, &mut samples, false).await?;
+        }
+
+        step_results.push(samples.stats(step.op));
+    }
+
+    let report = BenchmarkReport {
+        workload_name: workload.name,
+        git_commit: option_env!("VERGEN_GIT_SHA").unwrap_or("unknown").to_string(),
+        started_at: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs_f64(),
+        step_results,
+    };
+
+    if let Some(url) = results_server_url {
+        if let Err(e) = client.post(url).json(&report).send().await {
+            eprintln!("Failed to publish benchmark report to {}: {}", url, e);
+        }
+    }
+
+    Ok(report)
+}
+
+#[tauri::command]
+pub async fn run_benchmark(
+    state: tauri::State<'_, Arc<AppState>>,
+    workload_path: String,
+) -> Result<BenchmarkReport, String> {
+    let raw = std::fs::read_to_string(&workload_path)
+        .map_err(|e| format!("Failed to read workload file {}: {}", workload_path, e))?;
+    let workload: Workload =
+        serde_json::from_str(&raw).map_err(|e| format!("Failed to parse workload: {}", e))?;
+
+    run_workload(
+        &state.backend_url(),
+        state.results_server_url.as_deref(),
+        workload,
+    )
+    .await
+}
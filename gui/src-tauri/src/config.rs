@@ -0,0 +1,89 @@
+// Backend/preflight configuration loaded from a TOML or JSON file with env-var overrides,
+// following the OpenEthereum config-files / Garage config approach. Lets the orchestrator be
+// retargeted at different hardware profiles and model sets without a rebuild.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub backend_base_url: String,
+    pub ollama_url: String,
+    pub required_models: Vec<String>,
+    pub min_free_ram_gb: f64,
+    pub min_free_disk_gb: f64,
+    pub check_timeout_secs: u64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            backend_base_url: "http://127.0.0.1:8000".to_string(),
+            ollama_url: "http://127.0.0.1:11434".to_string(),
+            required_models: vec!["deepseek-r1:14b".to_string(), "qwen2.5-coder:7b".to_string()],
+            min_free_ram_gb: 2.0,
+            min_free_disk_gb: 5.0,
+            check_timeout_secs: 5,
+        }
+    }
+}
+
+/// Path the config is loaded from, overridable with `HARMONIC_CONFIG_PATH`.
+pub fn config_path() -> String {
+    std::env::var("HARMONIC_CONFIG_PATH").unwrap_or_else(|_| "harmonic.config.toml".to_string())
+}
+
+fn from_file(path: &str) -> Config {
+    let raw = match std::fs::read_to_string(path) {
+        Ok(raw) => raw,
+        Err(_) => return Config::default(),
+    };
+
+    let parsed = if path.ends_with(".json") {
+        serde_json::from_str(&raw).map_err(|e| e.to_string())
+    } else {
+        toml::from_str(&raw).map_err(|e| e.to_string())
+    };
+
+    match parsed {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Failed to parse config file {}: {} - using defaults", path, e);
+            Config::default()
+        }
+    }
+}
+
+fn apply_env_overrides(mut config: Config) -> Config {
+    if let Ok(v) = std::env::var("HARMONIC_BACKEND_URL") {
+        config.backend_base_url = v;
+    }
+    if let Ok(v) = std::env::var("HARMONIC_OLLAMA_URL") {
+        config.ollama_url = v;
+    }
+    if let Ok(v) = std::env::var("HARMONIC_REQUIRED_MODELS") {
+        config.required_models = v.split(',').map(|s| s.trim().to_string()).collect();
+    }
+    if let Ok(v) = std::env::var("HARMONIC_MIN_FREE_RAM_GB") {
+        if let Ok(v) = v.parse() {
+            config.min_free_ram_gb = v;
+        }
+    }
+    if let Ok(v) = std::env::var("HARMONIC_MIN_FREE_DISK_GB") {
+        if let Ok(v) = v.parse() {
+            config.min_free_disk_gb = v;
+        }
+    }
+    if let Ok(v) = std::env::var("HARMONIC_CHECK_TIMEOUT_SECS") {
+        if let Ok(v) = v.parse() {
+            config.check_timeout_secs = v;
+        }
+    }
+    config
+}
+
+/// Loads the config from `config_path()`, falling back to defaults when the file is absent or
+/// unparsable, then applies any `HARMONIC_*` environment overrides on top.
+pub fn load() -> Config {
+    apply_env_overrides(from_file(&config_path()))
+}
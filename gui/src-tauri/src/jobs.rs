@@ -0,0 +1,196 @@
+// Async job queue for evaluate/mutate, modeled on build-o-tron's ci_driver: submit_* commands
+// enqueue work and return a job id immediately instead of blocking the IPC call for up to 60s,
+// and every completed job's request/response is persisted under the app data dir so past
+// mutation variants and evaluation outputs survive restarts and can be diffed.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use crate::{evaluate_request, mutate_request, AppState, EvaluateRequest, MutateRequest};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum JobState {
+    Queued,
+    Running,
+    Done,
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JobStatus {
+    pub id: String,
+    pub state: JobState,
+    pub submitted_at: f64,
+    pub finished_at: Option<f64>,
+    pub result: Option<serde_json::Value>,
+}
+
+pub type JobMap = Arc<Mutex<HashMap<String, JobStatus>>>;
+
+fn now() -> f64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs_f64()
+}
+
+/// Reserves (creating if absent) the artifacts directory for a single job, tolerating the
+/// directory already existing from a prior run with the same id.
+fn reserve_artifacts_dir(app_handle: &tauri::AppHandle, job_id: &str) -> Result<PathBuf, String> {
+    let app_data_dir = app_handle
+        .path_resolver()
+        .app_data_dir()
+        .ok_or_else(|| "Could not resolve app data dir".to_string())?;
+    let dir = app_data_dir.join("artifacts").join(job_id);
+
+    match std::fs::create_dir_all(&dir) {
+        Ok(()) => Ok(dir),
+        Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => Ok(dir),
+        Err(e) => Err(format!("Failed to reserve artifacts dir: {}", e)),
+    }
+}
+
+fn write_artifact(dir: &PathBuf, name: &str, value: &impl Serialize) {
+    let path = dir.join(name);
+    match serde_json::to_vec_pretty(value) {
+        Ok(bytes) => {
+            if let Err(e) = std::fs::write(&path, bytes) {
+                eprintln!("Failed to write artifact {}: {}", path.display(), e);
+            }
+        }
+        Err(e) => eprintln!("Failed to serialize artifact {}: {}", path.display(), e),
+    }
+}
+
+fn set_job(jobs: &JobMap, job: JobStatus) {
+    jobs.lock().unwrap().insert(job.id.clone(), job);
+}
+
+#[tauri::command]
+pub async fn submit_evaluate(
+    app_handle: tauri::AppHandle,
+    state: tauri::State<'_, Arc<AppState>>,
+    request: EvaluateRequest,
+) -> Result<String, String> {
+    let job_id = uuid::Uuid::new_v4().to_string();
+    set_job(
+        &state.jobs,
+        JobStatus {
+            id: job_id.clone(),
+            state: JobState::Queued,
+            submitted_at: now(),
+            finished_at: None,
+            result: None,
+        },
+    );
+
+    let state = state.inner().clone();
+    let id = job_id.clone();
+    tokio::spawn(async move {
+        let jobs = state.jobs.clone();
+        jobs.lock().unwrap().get_mut(&id).unwrap().state = JobState::Running;
+
+        let artifacts_dir = reserve_artifacts_dir(&app_handle, &id).ok();
+        if let Some(dir) = &artifacts_dir {
+            write_artifact(dir, "request.json", &request);
+        }
+
+        let outcome = evaluate_request(&state, request).await;
+        let (job_state, result) = match &outcome {
+            Ok(response) => {
+                if let Some(dir) = &artifacts_dir {
+                    write_artifact(dir, "response.json", response);
+                }
+                (JobState::Done, serde_json::to_value(response).ok())
+            }
+            Err(e) => {
+                if let Some(dir) = &artifacts_dir {
+                    write_artifact(dir, "error.json", &serde_json::json!({ "error": e }));
+                }
+                (JobState::Failed, Some(serde_json::json!({ "error": e })))
+            }
+        };
+
+        let mut jobs = jobs.lock().unwrap();
+        let job = jobs.get_mut(&id).unwrap();
+        job.state = job_state;
+        job.finished_at = Some(now());
+        job.result = result;
+    });
+
+    Ok(job_id)
+}
+
+#[tauri::command]
+pub async fn submit_mutate(
+    app_handle: tauri::AppHandle,
+    state: tauri::State<'_, Arc<AppState>>,
+    request: MutateRequest,
+) -> Result<String, String> {
+    let job_id = uuid::Uuid::new_v4().to_string();
+    set_job(
+        &state.jobs,
+        JobStatus {
+            id: job_id.clone(),
+            state: JobState::Queued,
+            submitted_at: now(),
+            finished_at: None,
+            result: None,
+        },
+    );
+
+    let state = state.inner().clone();
+    let id = job_id.clone();
+    tokio::spawn(async move {
+        let jobs = state.jobs.clone();
+        jobs.lock().unwrap().get_mut(&id).unwrap().state = JobState::Running;
+
+        let artifacts_dir = reserve_artifacts_dir(&app_handle, &id).ok();
+        if let Some(dir) = &artifacts_dir {
+            write_artifact(dir, "request.json", &request);
+        }
+
+        let outcome = mutate_request(&state, request).await;
+        let (job_state, result) = match &outcome {
+            Ok(response) => {
+                if let Some(dir) = &artifacts_dir {
+                    write_artifact(dir, "response.json", response);
+                }
+                (JobState::Done, serde_json::to_value(response).ok())
+            }
+            Err(e) => {
+                if let Some(dir) = &artifacts_dir {
+                    write_artifact(dir, "error.json", &serde_json::json!({ "error": e }));
+                }
+                (JobState::Failed, Some(serde_json::json!({ "error": e })))
+            }
+        };
+
+        let mut jobs = jobs.lock().unwrap();
+        let job = jobs.get_mut(&id).unwrap();
+        job.state = job_state;
+        job.finished_at = Some(now());
+        job.result = result;
+    });
+
+    Ok(job_id)
+}
+
+#[tauri::command]
+pub fn get_job(state: tauri::State<'_, Arc<AppState>>, id: String) -> Result<JobStatus, String> {
+    state
+        .jobs
+        .lock()
+        .unwrap()
+        .get(&id)
+        .cloned()
+        .ok_or_else(|| format!("No job with id {}", id))
+}
+
+#[tauri::command]
+pub fn list_jobs(state: tauri::State<'_, Arc<AppState>>) -> Vec<JobStatus> {
+    state.jobs.lock().unwrap().values().cloned().collect()
+}
@@ -9,57 +9,65 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
-use sysinfo::{System, SystemExt};
-use tauri::State;
+use sysinfo::{DiskExt, System, SystemExt};
+use tauri::{Manager, State};
+use tokio::sync::RwLock;
+
+mod bench;
+mod config;
+mod jobs;
+mod metrics_server;
+mod telemetry_stream;
+mod window_manager;
 
 // ============================================================================
 // DATA STRUCTURES
 // ============================================================================
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-struct DiagnosticsResult {
+pub(crate) struct DiagnosticsResult {
     status: String,  // "OK", "WARNING", "ERROR"
-    checks: HashMap<String, CheckResult>,
+    pub(crate) checks: HashMap<String, CheckResult>,
     timestamp: f64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-struct CheckResult {
-    passed: bool,
+pub(crate) struct CheckResult {
+    pub(crate) passed: bool,
     message: String,
     severity: String,  // "info", "warning", "error"
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-struct EvaluateRequest {
+pub(crate) struct EvaluateRequest {
     goal: String,
     output: String,
     rubric_version: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-struct EvaluateResponse {
-    quality_score: f64,
-    delta_score: f64,
+pub(crate) struct EvaluateResponse {
+    pub(crate) quality_score: f64,
+    pub(crate) delta_score: f64,
     robust_pct: f64,
-    cache_hit: bool,
+    pub(crate) cache_hit: bool,
     time_ms: f64,
     routing_path: String,
     violations: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-struct MutateRequest {
+pub(crate) struct MutateRequest {
     goal: String,
     current_workflow: String,
     arm: Option<String>,  // "auto" uses bandit selection
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-struct MutateResponse {
+pub(crate) struct MutateResponse {
     variant_id: String,
     arm: String,
-    delta_score: f64,
+    pub(crate) delta_score: f64,
     novelty: f64,
     workflow: String,
 }
@@ -107,20 +115,38 @@ struct WorkflowEdge {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-struct TelemetryMetrics {
-    tokens_per_sec: f64,
-    delta_score: f64,
-    cache_hit_rate: f64,
-    robust_pct: f64,
-    memory_use_mb: f64,
-    module_status: HashMap<String, String>,
+pub(crate) struct TelemetryMetrics {
+    pub(crate) tokens_per_sec: f64,
+    pub(crate) delta_score: f64,
+    pub(crate) cache_hit_rate: f64,
+    pub(crate) robust_pct: f64,
+    pub(crate) memory_use_mb: f64,
+    pub(crate) module_status: HashMap<String, String>,
 }
 
 // Shared state
 struct AppState {
-    backend_base_url: String,
-    preflight_passed: Arc<Mutex<bool>>,
-    diagnostics: Arc<Mutex<Option<DiagnosticsResult>>>,
+    // Preflight thresholds and backend URLs, reloadable via the reload_config command.
+    pub(crate) config: Arc<Mutex<config::Config>>,
+    pub(crate) preflight_passed: Arc<Mutex<bool>>,
+    pub(crate) diagnostics: Arc<Mutex<Option<DiagnosticsResult>>>,
+    // Optional results-server URL that benchmark reports are POSTed to for cross-run comparison.
+    results_server_url: Option<String>,
+    // Last telemetry snapshot fetched from the backend, scraped by the Prometheus endpoint.
+    pub(crate) last_telemetry: Arc<Mutex<Option<TelemetryMetrics>>>,
+    // Background telemetry poller state, shared with the subscribe_telemetry command.
+    pub(crate) telemetry_poller: Arc<Mutex<Option<tokio::task::JoinHandle<()>>>>,
+    pub(crate) telemetry_seq: Arc<Mutex<u64>>,
+    pub(crate) telemetry_tx: tokio::sync::broadcast::Sender<telemetry_stream::TelemetrySnapshot>,
+    // Async evaluate/mutate job queue; see jobs.rs.
+    pub(crate) jobs: jobs::JobMap,
+}
+
+impl AppState {
+    /// Backend base URL from the current config snapshot.
+    pub(crate) fn backend_url(&self) -> String {
+        self.config.lock().unwrap().backend_base_url.clone()
+    }
 }
 
 // ============================================================================
@@ -140,19 +166,48 @@ async fn check_ram(min_free_gb: f64) -> CheckResult {
     }
 }
 
-async fn check_disk(min_free_gb: f64) -> CheckResult {
-    // Simplified - would normally check actual disk space
-    CheckResult {
-        passed: true,
-        message: format!("Disk space check passed (>= {:.2} GB)", min_free_gb),
-        severity: "info".to_string(),
+/// Finds the disk whose mount point contains `path`, picking the longest matching mount point
+/// so e.g. `/home` is preferred over `/` when both are mounted. Falls back to the disk with the
+/// most available space if `path` doesn't resolve to any known mount point.
+fn find_volume_for_path<'a>(disks: &'a [sysinfo::Disk], path: &std::path::Path) -> Option<&'a sysinfo::Disk> {
+    disks
+        .iter()
+        .filter(|disk| path.starts_with(disk.mount_point()))
+        .max_by_key(|disk| disk.mount_point().as_os_str().len())
+        .or_else(|| disks.iter().max_by_key(|disk| disk.available_space()))
+}
+
+async fn check_disk(data_dir: &std::path::Path, min_free_gb: f64) -> CheckResult {
+    let mut sys = System::new_all();
+    sys.refresh_disks_list();
+    sys.refresh_disks();
+
+    match find_volume_for_path(sys.disks(), data_dir) {
+        Some(disk) => {
+            let available_gb = disk.available_space() as f64 / 1_073_741_824.0;
+            CheckResult {
+                passed: available_gb >= min_free_gb,
+                message: format!(
+                    "Available disk space on {}: {:.2} GB (required: {:.2} GB)",
+                    disk.mount_point().display(),
+                    available_gb,
+                    min_free_gb
+                ),
+                severity: if available_gb >= min_free_gb { "info".to_string() } else { "error".to_string() },
+            }
+        }
+        None => CheckResult {
+            passed: false,
+            message: format!("Could not find a disk volume for {}", data_dir.display()),
+            severity: "error".to_string(),
+        },
     }
 }
 
-async fn check_ollama() -> CheckResult {
+async fn check_ollama(ollama_url: &str, timeout_secs: u64) -> CheckResult {
     let client = reqwest::Client::new();
-    match client.get("http://127.0.0.1:11434/api/tags")
-        .timeout(std::time::Duration::from_secs(5))
+    match client.get(format!("{}/api/tags", ollama_url))
+        .timeout(std::time::Duration::from_secs(timeout_secs))
         .send()
         .await
     {
@@ -163,16 +218,16 @@ async fn check_ollama() -> CheckResult {
         },
         _ => CheckResult {
             passed: false,
-            message: "Ollama service not reachable at http://127.0.0.1:11434".to_string(),
+            message: format!("Ollama service not reachable at {}", ollama_url),
             severity: "error".to_string(),
         },
     }
 }
 
-async fn check_models(required_models: Vec<String>) -> CheckResult {
+async fn check_models(ollama_url: &str, timeout_secs: u64, required_models: Vec<String>) -> CheckResult {
     let client = reqwest::Client::new();
-    match client.get("http://127.0.0.1:11434/api/tags")
-        .timeout(std::time::Duration::from_secs(5))
+    match client.get(format!("{}/api/tags", ollama_url))
+        .timeout(std::time::Duration::from_secs(timeout_secs))
         .send()
         .await
     {
@@ -221,10 +276,10 @@ async fn check_models(required_models: Vec<String>) -> CheckResult {
     }
 }
 
-async fn check_backend_services(base_url: &str) -> CheckResult {
+async fn check_backend_services(base_url: &str, timeout_secs: u64) -> CheckResult {
     let client = reqwest::Client::new();
     match client.get(format!("{}/health", base_url))
-        .timeout(std::time::Duration::from_secs(5))
+        .timeout(std::time::Duration::from_secs(timeout_secs))
         .send()
         .await
     {
@@ -246,16 +301,24 @@ async fn check_backend_services(base_url: &str) -> CheckResult {
 // ============================================================================
 
 #[tauri::command]
-async fn run_diagnostics(state: State<'_, AppState>) -> Result<DiagnosticsResult, String> {
+async fn run_diagnostics(
+    app_handle: tauri::AppHandle,
+    state: State<'_, Arc<AppState>>,
+) -> Result<DiagnosticsResult, String> {
     let mut checks = HashMap::new();
+    let config = state.config.lock().unwrap().clone();
+    let data_dir = app_handle
+        .path_resolver()
+        .app_data_dir()
+        .unwrap_or_else(std::env::temp_dir);
 
     // Run all checks in parallel
     let (ram, disk, ollama, models, backend) = tokio::join!(
-        check_ram(2.0),
-        check_disk(5.0),
-        check_ollama(),
-        check_models(vec!["deepseek-r1:14b".to_string(), "qwen2.5-coder:7b".to_string()]),
-        check_backend_services(&state.backend_base_url)
+        check_ram(config.min_free_ram_gb),
+        check_disk(&data_dir, config.min_free_disk_gb),
+        check_ollama(&config.ollama_url, config.check_timeout_secs),
+        check_models(&config.ollama_url, config.check_timeout_secs, config.required_models.clone()),
+        check_backend_services(&config.backend_base_url, config.check_timeout_secs)
     );
 
     checks.insert("ram".to_string(), ram.clone());
@@ -292,18 +355,18 @@ async fn run_diagnostics(state: State<'_, AppState>) -> Result<DiagnosticsResult
     Ok(result)
 }
 
-#[tauri::command]
-async fn evaluate(
-    state: State<'_, AppState>,
-    request: EvaluateRequest
+/// Core of the `evaluate` command, factored out so the job queue (jobs.rs) can run it off the
+/// IPC call without duplicating the preflight check / backend call.
+pub(crate) async fn evaluate_request(
+    state: &AppState,
+    request: EvaluateRequest,
 ) -> Result<EvaluateResponse, String> {
-    // Check if preflight passed
     if !*state.preflight_passed.lock().unwrap() {
         return Err("Preflight checks failed - run diagnostics first".to_string());
     }
 
     let client = reqwest::Client::new();
-    let url = format!("{}/evaluate", state.backend_base_url);
+    let url = format!("{}/evaluate", state.backend_url());
 
     match client.post(&url)
         .json(&request)
@@ -322,16 +385,24 @@ async fn evaluate(
 }
 
 #[tauri::command]
-async fn mutate_workflow(
-    state: State<'_, AppState>,
-    request: MutateRequest
+async fn evaluate(
+    state: State<'_, Arc<AppState>>,
+    request: EvaluateRequest
+) -> Result<EvaluateResponse, String> {
+    evaluate_request(&state, request).await
+}
+
+/// Core of the `mutate_workflow` command, factored out for the job queue. See `evaluate_request`.
+pub(crate) async fn mutate_request(
+    state: &AppState,
+    request: MutateRequest,
 ) -> Result<MutateResponse, String> {
     if !*state.preflight_passed.lock().unwrap() {
         return Err("Preflight checks failed".to_string());
     }
 
     let client = reqwest::Client::new();
-    let url = format!("{}/mutate", state.backend_base_url);
+    let url = format!("{}/mutate", state.backend_url());
 
     match client.post(&url)
         .json(&request)
@@ -349,9 +420,17 @@ async fn mutate_workflow(
 }
 
 #[tauri::command]
-async fn get_bandit_status(state: State<'_, AppState>) -> Result<BanditStatus, String> {
+async fn mutate_workflow(
+    state: State<'_, Arc<AppState>>,
+    request: MutateRequest
+) -> Result<MutateResponse, String> {
+    mutate_request(&state, request).await
+}
+
+#[tauri::command]
+async fn get_bandit_status(state: State<'_, Arc<AppState>>) -> Result<BanditStatus, String> {
     let client = reqwest::Client::new();
-    let url = format!("{}/bandit/status", state.backend_base_url);
+    let url = format!("{}/bandit/status", state.backend_url());
 
     match client.get(&url).send().await {
         Ok(response) if response.status().is_success() => {
@@ -365,12 +444,12 @@ async fn get_bandit_status(state: State<'_, AppState>) -> Result<BanditStatus, S
 
 #[tauri::command]
 async fn create_memory_snapshot(
-    state: State<'_, AppState>,
+    state: State<'_, Arc<AppState>>,
     title: String,
     content: String
 ) -> Result<MemorySnapshot, String> {
     let client = reqwest::Client::new();
-    let url = format!("{}/memory/snapshot", state.backend_base_url);
+    let url = format!("{}/memory/snapshot", state.backend_url());
 
     let body = serde_json::json!({
         "title": title,
@@ -388,9 +467,9 @@ async fn create_memory_snapshot(
 }
 
 #[tauri::command]
-async fn get_workflow_dag(state: State<'_, AppState>) -> Result<WorkflowDAG, String> {
+async fn get_workflow_dag(state: State<'_, Arc<AppState>>) -> Result<WorkflowDAG, String> {
     let client = reqwest::Client::new();
-    let url = format!("{}/workflow/dag", state.backend_base_url);
+    let url = format!("{}/workflow/dag", state.backend_url());
 
     match client.get(&url).send().await {
         Ok(response) if response.status().is_success() => {
@@ -403,38 +482,102 @@ async fn get_workflow_dag(state: State<'_, AppState>) -> Result<WorkflowDAG, Str
 }
 
 #[tauri::command]
-async fn get_telemetry_metrics(state: State<'_, AppState>) -> Result<TelemetryMetrics, String> {
+async fn get_telemetry_metrics(state: State<'_, Arc<AppState>>) -> Result<TelemetryMetrics, String> {
     let client = reqwest::Client::new();
-    let url = format!("{}/telemetry/metrics", state.backend_base_url);
+    let url = format!("{}/telemetry/metrics", state.backend_url());
 
     match client.get(&url).send().await {
         Ok(response) if response.status().is_success() => {
-            response.json::<TelemetryMetrics>()
+            let metrics = response.json::<TelemetryMetrics>()
                 .await
-                .map_err(|e| format!("Failed to parse response: {}", e))
+                .map_err(|e| format!("Failed to parse response: {}", e))?;
+            *state.last_telemetry.lock().unwrap() = Some(metrics.clone());
+            Ok(metrics)
         },
         _ => Err("Failed to get telemetry metrics".to_string()),
     }
 }
 
 #[tauri::command]
-fn is_preflight_passed(state: State<'_, AppState>) -> bool {
+fn is_preflight_passed(state: State<'_, Arc<AppState>>) -> bool {
     *state.preflight_passed.lock().unwrap()
 }
 
+/// Re-reads the config file and env overrides so thresholds/model lists can change without a
+/// rebuild. Does not re-run diagnostics; call run_diagnostics afterwards to re-validate.
+#[tauri::command]
+fn reload_config(state: State<'_, Arc<AppState>>) -> config::Config {
+    let fresh = config::load();
+    *state.config.lock().unwrap() = fresh.clone();
+    fresh
+}
+
 // ============================================================================
 // MAIN
 // ============================================================================
 
 fn main() {
-    let app_state = AppState {
-        backend_base_url: "http://127.0.0.1:8000".to_string(),
+    let (telemetry_tx, _) = telemetry_stream::new_channel();
+
+    let app_state = Arc::new(AppState {
+        config: Arc::new(Mutex::new(config::load())),
         preflight_passed: Arc::new(Mutex::new(false)),
         diagnostics: Arc::new(Mutex::new(None)),
-    };
+        results_server_url: std::env::var("HARMONIC_BENCH_RESULTS_URL").ok(),
+        last_telemetry: Arc::new(Mutex::new(None)),
+        telemetry_poller: Arc::new(Mutex::new(None)),
+        telemetry_seq: Arc::new(Mutex::new(0)),
+        telemetry_tx,
+        jobs: Arc::new(Mutex::new(HashMap::new())),
+    });
+
+    let metrics_port: u16 = std::env::var("HARMONIC_METRICS_PORT")
+        .ok()
+        .and_then(|p| p.parse().ok())
+        .unwrap_or(9898);
+    let metrics_state = app_state.clone();
+    tauri::async_runtime::spawn(async move {
+        metrics_server::serve(metrics_port, metrics_state).await;
+    });
+
+    // Unity office windows: a gRPC node registry for federation, an OT-backed shared memory
+    // store, and the 40Hz audio cue subsystem are all app-wide, so they're built up front. The
+    // registry and shared memory are `.manage()`d alongside `WindowManager` itself, and audio is
+    // handed to `WindowManager` (hydrated from SQLite in `.setup`, since that needs the app
+    // handle's data dir, which isn't available yet here).
+    let node_registry = Arc::new(window_manager::federation::NodeRegistry::new());
+    let shared_memory = Arc::new(window_manager::shared_memory::SharedMemory::new());
+    // Audio cues are a nice-to-have, not essential to the app functioning (which also runs
+    // headless, given the Prometheus /metrics server above) - so a failure to open an output
+    // device degrades to a disabled cue subsystem instead of preventing startup.
+    let audio = Arc::new(window_manager::audio::Audio::start().unwrap_or_else(|e| {
+        eprintln!("Failed to start 40Hz audio subsystem, disabling audio cues: {}", e);
+        window_manager::audio::Audio::noop()
+    }));
 
     tauri::Builder::default()
         .manage(app_state)
+        .manage(node_registry.clone())
+        .manage(shared_memory)
+        .setup(move |app| {
+            let app_handle = app.handle();
+            let data_dir = app_handle
+                .path_resolver()
+                .app_data_dir()
+                .ok_or("Could not resolve app data dir")?;
+            std::fs::create_dir_all(&data_dir)?;
+            let store_path = data_dir.join("window_manager.sqlite");
+
+            let node_registry = node_registry.clone();
+            let audio = audio.clone();
+            let manager = tauri::async_runtime::block_on(async move {
+                let store = Arc::new(window_manager::store::Store::connect(&store_path.to_string_lossy()).await?);
+                window_manager::WindowManager::new_with_hydration(app_handle.clone(), node_registry, store, audio).await
+            })?;
+
+            app.manage(Arc::new(RwLock::new(manager)));
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             run_diagnostics,
             evaluate,
@@ -443,7 +586,32 @@ fn main() {
             create_memory_snapshot,
             get_workflow_dag,
             get_telemetry_metrics,
-            is_preflight_passed
+            is_preflight_passed,
+            bench::run_benchmark,
+            telemetry_stream::subscribe_telemetry,
+            jobs::submit_evaluate,
+            jobs::submit_mutate,
+            jobs::get_job,
+            jobs::list_jobs,
+            reload_config,
+            window_manager::create_office,
+            window_manager::close_office,
+            window_manager::get_offices,
+            window_manager::send_office_message,
+            window_manager::broadcast_message,
+            window_manager::update_presence,
+            window_manager::get_presences,
+            window_manager::register_remote_office,
+            window_manager::save_layout,
+            window_manager::restore_layout,
+            window_manager::set_office_muted,
+            window_manager::stop_all_audio,
+            window_manager::shared_memory::submit_shared_op,
+            window_manager::shared_memory::get_shared_doc,
+            window_manager::federation::connect_node,
+            window_manager::federation::disconnect_node,
+            window_manager::federation::list_connected_nodes,
+            window_manager::federation::rotate_node_token,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -0,0 +1,133 @@
+// Embedded HTTP server: a Prometheus scrape endpoint mirroring Garage's metrics.rs admin
+// endpoint, plus an SSE stream of telemetry_stream::TelemetrySnapshot for push-based dashboards.
+
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Method, Request, Response, Server, StatusCode};
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use tokio_stream::wrappers::BroadcastStream;
+use tokio_stream::StreamExt;
+
+use crate::AppState;
+
+fn escape_label_value(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn render_metrics(state: &AppState) -> String {
+    let mut out = String::new();
+
+    let telemetry = state.last_telemetry.lock().unwrap().clone();
+    if let Some(t) = telemetry {
+        out.push_str("# HELP harmonic_tokens_per_sec Backend evaluation throughput in tokens/sec.\n");
+        out.push_str("# TYPE harmonic_tokens_per_sec gauge\n");
+        out.push_str(&format!("harmonic_tokens_per_sec {}\n", t.tokens_per_sec));
+
+        out.push_str("# HELP harmonic_delta_score Mean quality delta from the last mutation round.\n");
+        out.push_str("# TYPE harmonic_delta_score gauge\n");
+        out.push_str(&format!("harmonic_delta_score {}\n", t.delta_score));
+
+        out.push_str("# HELP harmonic_cache_hit_rate Evaluation cache hit rate.\n");
+        out.push_str("# TYPE harmonic_cache_hit_rate gauge\n");
+        out.push_str(&format!("harmonic_cache_hit_rate {}\n", t.cache_hit_rate));
+
+        out.push_str("# HELP harmonic_robust_pct Percentage of evaluations passing robustness checks.\n");
+        out.push_str("# TYPE harmonic_robust_pct gauge\n");
+        out.push_str(&format!("harmonic_robust_pct {}\n", t.robust_pct));
+
+        out.push_str("# HELP harmonic_memory_use_mb Resident memory use of the backend in MB.\n");
+        out.push_str("# TYPE harmonic_memory_use_mb gauge\n");
+        out.push_str(&format!("harmonic_memory_use_mb {}\n", t.memory_use_mb));
+
+        out.push_str("# HELP harmonic_module_status Backend module status, 1 if the label's status is \"ok\".\n");
+        out.push_str("# TYPE harmonic_module_status gauge\n");
+        for (module, status) in &t.module_status {
+            let value = if status.eq_ignore_ascii_case("ok") { 1 } else { 0 };
+            out.push_str(&format!(
+                "harmonic_module_status{{module=\"{}\"}} {}\n",
+                escape_label_value(module),
+                value
+            ));
+        }
+    }
+
+    out.push_str("# HELP harmonic_preflight_passed Whether the last preflight run passed, 1 or 0.\n");
+    out.push_str("# TYPE harmonic_preflight_passed gauge\n");
+    out.push_str(&format!(
+        "harmonic_preflight_passed {}\n",
+        *state.preflight_passed.lock().unwrap() as u8
+    ));
+
+    out.push_str("# HELP harmonic_preflight_check_passed Per-check pass/fail from the last diagnostics run.\n");
+    out.push_str("# TYPE harmonic_preflight_check_passed gauge\n");
+    if let Some(diagnostics) = state.diagnostics.lock().unwrap().clone() {
+        for (check, result) in &diagnostics.checks {
+            out.push_str(&format!(
+                "harmonic_preflight_check_passed{{check=\"{}\"}} {}\n",
+                escape_label_value(check),
+                result.passed as u8
+            ));
+        }
+    }
+
+    out
+}
+
+fn sse_stream(state: &AppState) -> impl futures::Stream<Item = Result<Vec<u8>, Infallible>> {
+    BroadcastStream::new(state.telemetry_tx.subscribe()).map(|snapshot| {
+        let line = match snapshot {
+            Ok(snapshot) => format!(
+                "id: {}\ndata: {}\n\n",
+                snapshot.seq,
+                serde_json::to_string(&snapshot).unwrap_or_default()
+            ),
+            // A slow subscriber that missed messages; tell the client to reconnect from scratch.
+            Err(_) => "event: drop\ndata: {}\n\n".to_string(),
+        };
+        Ok(line.into_bytes())
+    })
+}
+
+async fn handle(req: Request<Body>, state: std::sync::Arc<AppState>) -> Result<Response<Body>, Infallible> {
+    match (req.method(), req.uri().path()) {
+        (&Method::GET, "/metrics") => {
+            let body = render_metrics(&state);
+            Ok(Response::builder()
+                .status(StatusCode::OK)
+                .header("Content-Type", "text/plain; version=0.0.4")
+                .body(Body::from(body))
+                .unwrap())
+        }
+        (&Method::GET, "/telemetry/stream") => {
+            let body = Body::wrap_stream(sse_stream(&state));
+            Ok(Response::builder()
+                .status(StatusCode::OK)
+                .header("Content-Type", "text/event-stream")
+                .header("Cache-Control", "no-cache")
+                .body(body)
+                .unwrap())
+        }
+        _ => Ok(Response::builder()
+            .status(StatusCode::NOT_FOUND)
+            .body(Body::from("not found"))
+            .unwrap()),
+    }
+}
+
+/// Runs the HTTP server (`/metrics` and `/telemetry/stream`) on 127.0.0.1 at the given port.
+/// Does not return until the server stops.
+pub async fn serve(port: u16, state: std::sync::Arc<AppState>) {
+    let addr = SocketAddr::from(([127, 0, 0, 1], port));
+
+    let make_svc = make_service_fn(move |_conn| {
+        let state = state.clone();
+        async move { Ok::<_, Infallible>(service_fn(move |req| handle(req, state.clone()))) }
+    });
+
+    let server = Server::bind(&addr).serve(make_svc);
+    println!("Prometheus metrics endpoint listening on http://{}/metrics", addr);
+
+    if let Err(e) = server.await {
+        eprintln!("Metrics server error: {}", e);
+    }
+}
@@ -0,0 +1,94 @@
+// Push-based telemetry/diagnostics streaming: a background poller publishes snapshots both as
+// Tauri events (for the webview) and over the embedded HTTP server's SSE endpoint, so clients
+// don't have to hammer get_telemetry_metrics/run_diagnostics on a timer.
+
+use serde::Serialize;
+use std::sync::Arc;
+use tauri::Manager;
+use tokio::sync::broadcast;
+
+use crate::{AppState, DiagnosticsResult, TelemetryMetrics};
+
+pub const TELEMETRY_EVENT: &str = "telemetry_stream";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TelemetrySnapshot {
+    pub seq: u64,
+    pub metrics: Option<TelemetryMetrics>,
+    pub diagnostics: Option<DiagnosticsResult>,
+    pub timestamp: f64,
+}
+
+async fn fetch_telemetry(base_url: &str) -> Option<TelemetryMetrics> {
+    let client = reqwest::Client::new();
+    let response = client
+        .get(format!("{}/telemetry/metrics", base_url))
+        .send()
+        .await
+        .ok()?;
+    if !response.status().is_success() {
+        return None;
+    }
+    response.json::<TelemetryMetrics>().await.ok()
+}
+
+async fn poll_loop(app_handle: tauri::AppHandle, state: Arc<AppState>, interval_ms: u64) {
+    let mut interval = tokio::time::interval(std::time::Duration::from_millis(interval_ms));
+    loop {
+        interval.tick().await;
+
+        let metrics = fetch_telemetry(&state.backend_url()).await;
+        if let Some(m) = metrics.clone() {
+            *state.last_telemetry.lock().unwrap() = Some(m);
+        }
+        let diagnostics = state.diagnostics.lock().unwrap().clone();
+
+        let seq = {
+            let mut seq = state.telemetry_seq.lock().unwrap();
+            *seq += 1;
+            *seq
+        };
+
+        let snapshot = TelemetrySnapshot {
+            seq,
+            metrics,
+            diagnostics,
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs_f64(),
+        };
+
+        // Ignore send errors: no SSE/webview listeners is a normal idle state, not a failure.
+        let _ = state.telemetry_tx.send(snapshot.clone());
+        let _ = app_handle.emit_all(TELEMETRY_EVENT, &snapshot);
+    }
+}
+
+/// Starts (or restarts with a new interval) the background telemetry poller. Passing
+/// `interval_ms == 0` stops the poller without starting a new one.
+#[tauri::command]
+pub async fn subscribe_telemetry(
+    app_handle: tauri::AppHandle,
+    state: tauri::State<'_, Arc<AppState>>,
+    interval_ms: u64,
+) -> Result<(), String> {
+    if let Some(handle) = state.telemetry_poller.lock().unwrap().take() {
+        handle.abort();
+    }
+
+    if interval_ms == 0 {
+        return Ok(());
+    }
+
+    let state_clone = state.inner().clone();
+    let join_handle = tokio::spawn(poll_loop(app_handle, state_clone, interval_ms));
+    *state.telemetry_poller.lock().unwrap() = Some(join_handle);
+
+    Ok(())
+}
+
+/// Creates the broadcast channel used to fan out snapshots to SSE subscribers.
+pub fn new_channel() -> (broadcast::Sender<TelemetrySnapshot>, broadcast::Receiver<TelemetrySnapshot>) {
+    broadcast::channel(64)
+}
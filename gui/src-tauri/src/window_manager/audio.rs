@@ -0,0 +1,174 @@
+// 40Hz harmonic audio cue subsystem: a background thread owns the cpal output stream and mixes
+// whatever tones are currently playing, fed through an mpsc channel of playback requests so
+// `play_tone`/`play_cue` can be called from async Tauri command contexts without touching the
+// stream directly. Tones are synthesized in-process (plain sine generators, no sample files) so
+// the 40Hz entrainment pulse can be retuned at runtime instead of baked into a bundled asset.
+
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use std::f64::consts::PI;
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+/// Named cues fired on office lifecycle events, so callers don't need to know tone frequencies.
+#[derive(Debug, Clone, Copy)]
+pub enum Cue {
+    OfficeOpened,
+    InterOfficeMessage,
+    WorkflowCompleted,
+    /// The 40Hz isochronic entrainment pulse the crate is named after.
+    EmergencyBroadcast,
+}
+
+/// A single active tone: `generator` maps elapsed seconds to a sample in `[-1.0, 1.0]`.
+struct Voice {
+    generator: Box<dyn FnMut(f64) -> f32 + Send>,
+    elapsed_samples: u64,
+    duration_samples: u64,
+}
+
+enum AudioCommand {
+    Play(Box<dyn FnMut(f64) -> f32 + Send>, Duration),
+    StopAll,
+}
+
+/// Handle to the background audio thread. Cheap to clone and share as app-wide state; all actual
+/// mixing happens on the dedicated thread spawned by `start`.
+#[derive(Clone)]
+pub struct Audio {
+    tx: mpsc::Sender<AudioCommand>,
+}
+
+impl Audio {
+    /// Spawns the background output thread and blocks until it has either opened the output
+    /// device and started the stream, or failed to do so - returning that outcome as this
+    /// call's `Result`, rather than letting init failures disappear into an `eprintln!` on a
+    /// detached thread.
+    pub fn start() -> Result<Self, String> {
+        let (tx, rx) = mpsc::channel();
+        let (ready_tx, ready_rx) = std::sync::mpsc::channel();
+        thread::spawn(move || run_output_thread(rx, ready_tx));
+
+        ready_rx
+            .recv()
+            .map_err(|_| "Audio output thread exited before it could start".to_string())??;
+
+        Ok(Self { tx })
+    }
+
+    /// A handle backed by no output thread at all - every command silently goes nowhere, since the
+    /// receiving end is dropped immediately. Lets callers fall back to a disabled cue subsystem
+    /// (e.g. no output device in a headless/server environment) without making `Audio` itself
+    /// `Option`-wrapped everywhere it's threaded through.
+    pub fn noop() -> Self {
+        let (tx, _rx) = mpsc::channel();
+        Self { tx }
+    }
+
+    /// Plays a plain sine tone at `freq_hz` for `duration`, mixed with whatever else is playing.
+    pub fn play_tone(&self, freq_hz: f32, duration: Duration) {
+        let freq = freq_hz as f64;
+        let generator = move |t: f64| ((2.0 * PI * freq * t).sin() * 0.2) as f32;
+        let _ = self.tx.send(AudioCommand::Play(Box::new(generator), duration));
+    }
+
+    /// Plays a 40Hz isochronic pulse: a carrier tone amplitude-modulated at `beat_hz`. At
+    /// `beat_hz = 40.0` this is the crate's namesake entrainment cue.
+    pub fn play_isochronic_pulse(&self, beat_hz: f32, duration: Duration) {
+        let carrier_hz = 200.0_f64;
+        let beat = beat_hz as f64;
+        let generator = move |t: f64| {
+            let carrier = (2.0 * PI * carrier_hz * t).sin();
+            let envelope = 0.5 * (1.0 + (2.0 * PI * beat * t).sin());
+            (carrier * envelope * 0.3) as f32
+        };
+        let _ = self.tx.send(AudioCommand::Play(Box::new(generator), duration));
+    }
+
+    /// Plays the tone associated with a named lifecycle event.
+    pub fn play_cue(&self, cue: Cue) {
+        match cue {
+            Cue::OfficeOpened => self.play_tone(880.0, Duration::from_millis(120)),
+            Cue::InterOfficeMessage => self.play_tone(440.0, Duration::from_millis(80)),
+            Cue::WorkflowCompleted => self.play_tone(660.0, Duration::from_millis(250)),
+            Cue::EmergencyBroadcast => self.play_isochronic_pulse(40.0, Duration::from_secs(2)),
+        }
+    }
+
+    /// Silences every currently playing voice immediately.
+    pub fn stop_all(&self) {
+        let _ = self.tx.send(AudioCommand::StopAll);
+    }
+}
+
+fn run_output_thread(rx: mpsc::Receiver<AudioCommand>, ready_tx: mpsc::Sender<Result<(), String>>) {
+    let host = cpal::default_host();
+    let device = match host.default_output_device() {
+        Some(device) => device,
+        None => {
+            let _ = ready_tx.send(Err("No audio output device available".to_string()));
+            return;
+        }
+    };
+
+    let config = match device.default_output_config() {
+        Ok(config) => config,
+        Err(e) => {
+            let _ = ready_tx.send(Err(format!("Failed to read default output config: {}", e)));
+            return;
+        }
+    };
+
+    let sample_rate = config.sample_rate().0 as f64;
+    let channels = config.channels() as usize;
+    let voices: Arc<Mutex<Vec<Voice>>> = Arc::new(Mutex::new(Vec::new()));
+
+    let stream_voices = voices.clone();
+    let stream = device.build_output_stream(
+        &config.into(),
+        move |data: &mut [f32], _: &cpal::OutputCallbackInfo| {
+            let mut voices = stream_voices.lock().unwrap();
+            for frame in data.chunks_mut(channels) {
+                let mut mixed = 0.0f32;
+                voices.retain_mut(|voice| {
+                    let t = voice.elapsed_samples as f64 / sample_rate;
+                    mixed += (voice.generator)(t);
+                    voice.elapsed_samples += 1;
+                    voice.elapsed_samples < voice.duration_samples
+                });
+                for sample in frame.iter_mut() {
+                    *sample = mixed;
+                }
+            }
+        },
+        |e| eprintln!("Audio output stream error: {}", e),
+        None,
+    );
+
+    let stream = match stream {
+        Ok(stream) => stream,
+        Err(e) => {
+            let _ = ready_tx.send(Err(format!("Failed to build output stream: {}", e)));
+            return;
+        }
+    };
+
+    if let Err(e) = stream.play() {
+        let _ = ready_tx.send(Err(format!("Failed to start output stream: {}", e)));
+        return;
+    }
+
+    let _ = ready_tx.send(Ok(()));
+
+    // Own the stream for the thread's lifetime; commands just mutate the shared voice list.
+    while let Ok(command) = rx.recv() {
+        match command {
+            AudioCommand::Play(generator, duration) => {
+                let duration_samples = (duration.as_secs_f64() * sample_rate) as u64;
+                voices.lock().unwrap().push(Voice { generator, elapsed_samples: 0, duration_samples });
+            }
+            AudioCommand::StopAll => voices.lock().unwrap().clear(),
+        }
+    }
+}
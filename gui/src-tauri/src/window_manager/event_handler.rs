@@ -0,0 +1,75 @@
+// Trait-based async event handling + command routing for office IPC, replacing the println!
+// stubs in setup_window_ipc with something programmable: implement OfficeEventHandler once and
+// register it on the WindowManager, and text payloads prefixed with e.g. `!trade` are parsed
+// into a typed OfficeCommand and dispatched to the matching office.
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::window_manager::OfficeType;
+
+/// Implemented once per application to receive office IPC events instead of them being printed.
+#[async_trait]
+pub trait OfficeEventHandler: Send + Sync {
+    async fn on_memory_access_request(&self, office_type: &OfficeType, payload: Option<&str>);
+    async fn on_inter_office_message(&self, office_type: &OfficeType, payload: Option<&str>);
+    async fn on_office_message(&self, office_type: &OfficeType, message: &Value);
+}
+
+/// Default handler that preserves the original println! behavior for anyone who hasn't
+/// registered a real handler yet.
+pub struct LoggingEventHandler;
+
+#[async_trait]
+impl OfficeEventHandler for LoggingEventHandler {
+    async fn on_memory_access_request(&self, office_type: &OfficeType, payload: Option<&str>) {
+        println!("Office {:?} requesting memory access: {:?}", office_type, payload);
+    }
+
+    async fn on_inter_office_message(&self, office_type: &OfficeType, payload: Option<&str>) {
+        println!("Inter-office message for {:?}: {:?}", office_type, payload);
+    }
+
+    async fn on_office_message(&self, office_type: &OfficeType, message: &Value) {
+        println!("Office message for {:?}: {:?}", office_type, message);
+    }
+}
+
+/// A parsed `!command arg1 arg2 ...` payload, ready to be routed to the office that handles it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OfficeCommand {
+    pub name: String,
+    pub args: Vec<String>,
+}
+
+/// A structured reply emitted back to the window that issued an OfficeCommand.
+#[derive(Debug, Clone, Serialize)]
+pub struct OfficeCommandReply {
+    pub command: String,
+    pub ok: bool,
+    pub message: String,
+}
+
+/// Parses a text payload beginning with `prefix` (e.g. `!`) into an `OfficeCommand`. Returns
+/// `None` if the payload doesn't start with the prefix.
+pub fn parse_command(prefix: &str, text: &str) -> Option<OfficeCommand> {
+    let rest = text.trim().strip_prefix(prefix)?;
+    let mut parts = rest.split_whitespace();
+    let name = parts.next()?.to_string();
+    let args = parts.map(|s| s.to_string()).collect();
+    Some(OfficeCommand { name, args })
+}
+
+/// Maps a command name to the office type responsible for handling it. Unknown commands fall
+/// through to `None`, which the caller should reply to with an error rather than silently drop.
+pub fn route_command(command: &OfficeCommand) -> Option<OfficeType> {
+    match command.name.as_str() {
+        "trade" => Some(OfficeType::TradingOffice),
+        "crypto" => Some(OfficeType::CryptoOffice),
+        "plan" => Some(OfficeType::TravelPlanner),
+        "legal" => Some(OfficeType::LegalOffice),
+        "security" => Some(OfficeType::Security),
+        _ => None,
+    }
+}
@@ -0,0 +1,169 @@
+// Federated remote offices: a WindowManager can connect to a remote Unity node over gRPC and
+// transparently route office traffic to it, instead of every office being a local window on
+// http://localhost:1420. Token rotation is transparent to in-flight calls because the auth
+// interceptor reads the current token from a `watch::Receiver` on every request.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::{watch, RwLock};
+use tonic::service::Interceptor;
+use tonic::transport::{Channel, Endpoint};
+use tonic::{Request, Status};
+
+use crate::window_manager::OfficeType;
+
+pub mod proto {
+    tonic::include_proto!("unity.federation");
+}
+
+use proto::unity_federation_client::UnityFederationClient;
+use proto::{BroadcastRequest, OpenOfficeRequest, SendMessageRequest};
+
+/// Injects the current auth token into every outgoing request's metadata. Reading from a
+/// `watch::Receiver` means rotating the token (pushing a new value into the paired `Sender`)
+/// takes effect on the next call without touching in-flight requests or reconnecting.
+#[derive(Clone)]
+pub struct TokenInterceptor {
+    token: watch::Receiver<String>,
+}
+
+impl Interceptor for TokenInterceptor {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        let token = self.token.borrow().clone();
+        let value = format!("Bearer {}", token)
+            .parse()
+            .map_err(|_| Status::invalid_argument("invalid auth token"))?;
+        request.metadata_mut().insert("authorization", value);
+        Ok(request)
+    }
+}
+
+pub struct RemoteNode {
+    pub id: String,
+    pub url: String,
+    token_tx: watch::Sender<String>,
+    client: UnityFederationClient<tonic::service::intercepted::InterceptedService<Channel, TokenInterceptor>>,
+}
+
+impl RemoteNode {
+    async fn connect(id: String, url: String, token: String) -> Result<Self, String> {
+        let endpoint: Endpoint = url.parse().map_err(|e| format!("Invalid node url: {}", e))?;
+        let channel = endpoint
+            .connect()
+            .await
+            .map_err(|e| format!("Failed to connect to node {}: {}", url, e))?;
+
+        let (token_tx, token_rx) = watch::channel(token);
+        let client = UnityFederationClient::with_interceptor(channel, TokenInterceptor { token: token_rx });
+
+        Ok(Self { id, url, token_tx, client })
+    }
+
+    /// Rotates the auth token used for future requests without reconnecting.
+    pub fn rotate_token(&self, token: String) {
+        let _ = self.token_tx.send(token);
+    }
+
+    pub async fn open_office(&self, office_type: &OfficeType, memory_consent: bool) -> Result<String, String> {
+        let mut client = self.client.clone();
+        let response = client
+            .open_office(OpenOfficeRequest {
+                office_type: office_type.to_string(),
+                memory_consent,
+            })
+            .await
+            .map_err(|e| format!("Remote open_office failed: {}", e))?;
+        Ok(response.into_inner().window_id)
+    }
+
+    pub async fn send_message(&self, office_type: &OfficeType, message: &serde_json::Value) -> Result<(), String> {
+        let mut client = self.client.clone();
+        client
+            .send_message(SendMessageRequest {
+                office_type: office_type.to_string(),
+                message_json: message.to_string(),
+            })
+            .await
+            .map_err(|e| format!("Remote send_message failed: {}", e))?;
+        Ok(())
+    }
+
+    pub async fn broadcast(&self, message: &serde_json::Value) -> Result<(), String> {
+        let mut client = self.client.clone();
+        client
+            .broadcast_to_all(BroadcastRequest { message_json: message.to_string() })
+            .await
+            .map_err(|e| format!("Remote broadcast failed: {}", e))?;
+        Ok(())
+    }
+}
+
+/// Registry of connected remote nodes, keyed by the id the caller chose in `connect_node`.
+pub struct NodeRegistry {
+    nodes: Arc<RwLock<HashMap<String, Arc<RemoteNode>>>>,
+}
+
+impl NodeRegistry {
+    pub fn new() -> Self {
+        Self { nodes: Arc::new(RwLock::new(HashMap::new())) }
+    }
+
+    pub async fn connect(&self, id: String, url: String, token: String) -> Result<(), String> {
+        let node = RemoteNode::connect(id.clone(), url, token).await?;
+        self.nodes.write().await.insert(id, Arc::new(node));
+        Ok(())
+    }
+
+    pub async fn disconnect(&self, id: &str) -> Result<(), String> {
+        self.nodes
+            .write()
+            .await
+            .remove(id)
+            .map(|_| ())
+            .ok_or_else(|| format!("No connected node with id {}", id))
+    }
+
+    pub async fn get(&self, id: &str) -> Option<Arc<RemoteNode>> {
+        self.nodes.read().await.get(id).cloned()
+    }
+
+    pub async fn connected_ids(&self) -> Vec<String> {
+        self.nodes.read().await.keys().cloned().collect()
+    }
+
+    /// Rotates the auth token for an already-connected node, transparently to any in-flight call.
+    pub async fn rotate_token(&self, id: &str, token: String) -> Result<(), String> {
+        let node = self.get(id).await.ok_or_else(|| format!("No connected node with id {}", id))?;
+        node.rotate_token(token);
+        Ok(())
+    }
+}
+
+#[tauri::command]
+pub async fn connect_node(
+    state: tauri::State<'_, Arc<NodeRegistry>>,
+    id: String,
+    url: String,
+    token: String,
+) -> Result<(), String> {
+    state.connect(id, url, token).await
+}
+
+#[tauri::command]
+pub async fn disconnect_node(state: tauri::State<'_, Arc<NodeRegistry>>, id: String) -> Result<(), String> {
+    state.disconnect(&id).await
+}
+
+#[tauri::command]
+pub async fn list_connected_nodes(state: tauri::State<'_, Arc<NodeRegistry>>) -> Result<Vec<String>, String> {
+    Ok(state.connected_ids().await)
+}
+
+#[tauri::command]
+pub async fn rotate_node_token(
+    state: tauri::State<'_, Arc<NodeRegistry>>,
+    id: String,
+    token: String,
+) -> Result<(), String> {
+    state.rotate_token(&id, token).await
+}
@@ -0,0 +1,1001 @@
+use futures::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tauri::{Manager, Window, WindowBuilder, WindowUrl};
+use tokio::sync::{oneshot, RwLock};
+use uuid::Uuid;
+
+pub mod audio;
+pub mod event_handler;
+pub mod federation;
+pub mod presence;
+pub mod shared_memory;
+pub mod store;
+
+use event_handler::{LoggingEventHandler, OfficeCommandReply, OfficeEventHandler};
+use federation::NodeRegistry;
+use audio::{Audio, Cue};
+use presence::{Presence, PresenceRemoved, PRESENCE_HEARTBEAT_INTERVAL_SECS, PRESENCE_UPDATE_EVENT};
+use store::Store;
+
+/// Represents a Unity Office window instance
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OfficeWindow {
+    pub id: String,
+    pub office_type: OfficeType,
+    pub title: String,
+    pub position: Option<(i32, i32)>,
+    pub size: (u32, u32),
+    pub memory_consent: bool,
+    pub shared_memory_ttl: u64, // in seconds
+    // Suppresses this office's audio cues (window opened, inter-office message) without
+    // affecting other offices or system-wide cues like `broadcast_to_all`'s.
+    pub muted: bool,
+}
+
+/// All 43 office types in Unity
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum OfficeType {
+    // Core Offices
+    Orchestrator,
+    Memory,
+    Security,
+
+    // Financial Offices
+    TradingOffice,
+    CryptoOffice,
+    TaxAdvisor,
+    FinancialAdvisor,
+    BankingOffice,
+
+    // Legal & Compliance
+    LegalOffice,
+    ComplianceOfficer,
+    ContractAnalyst,
+    IntellectualProperty,
+
+    // Travel & Lifestyle
+    TravelPlanner,
+    RestaurantConcierge,
+    EventCoordinator,
+    PersonalShopper,
+
+    // Health & Wellness
+    PhysicalTrainer,
+    Nutritionist,
+    SleepCoach,
+    Psychologist,
+    MedicalAdvisor,
+
+    // Creative & Media
+    ContentCreator,
+    VideoEditor,
+    GraphicDesigner,
+    MusicProducer,
+
+    // Technical
+    DevOpsEngineer,
+    DataAnalyst,
+    SecurityAnalyst,
+    CloudArchitect,
+
+    // Research & Education
+    ResearchAnalyst,
+    EducationAdvisor,
+    LanguageTutor,
+    SkillCoach,
+
+    // Spiritual & Personal
+    TarotReader,
+    Astrologer,
+    MeditationGuide,
+    LifeCoach,
+
+    // Home & Kitchen
+    KitchenManager,
+    HomeAutomation,
+    MaintenanceScheduler,
+
+    // Special Operations
+    QuantumComputing,
+    EmergencyResponse,
+}
+
+impl OfficeType {
+    pub fn to_string(&self) -> String {
+        match self {
+            Self::Orchestrator => "Orchestrator".to_string(),
+            Self::Memory => "Memory Graph".to_string(),
+            Self::Security => "Security Office".to_string(),
+            Self::TradingOffice => "Trading Office".to_string(),
+            Self::CryptoOffice => "Crypto Office".to_string(),
+            Self::TaxAdvisor => "Tax Advisor".to_string(),
+            Self::FinancialAdvisor => "Financial Advisor".to_string(),
+            Self::BankingOffice => "Banking Office".to_string(),
+            Self::LegalOffice => "Legal Office".to_string(),
+            Self::ComplianceOfficer => "Compliance Officer".to_string(),
+            Self::ContractAnalyst => "Contract Analyst".to_string(),
+            Self::IntellectualProperty => "IP Office".to_string(),
+            Self::TravelPlanner => "Travel Planner".to_string(),
+            Self::RestaurantConcierge => "Restaurant Concierge".to_string(),
+            Self::EventCoordinator => "Event Coordinator".to_string(),
+            Self::PersonalShopper => "Personal Shopper".to_string(),
+            Self::PhysicalTrainer => "Physical Trainer".to_string(),
+            Self::Nutritionist => "Nutritionist".to_string(),
+            Self::SleepCoach => "Sleep Coach".to_string(),
+            Self::Psychologist => "Psychologist".to_string(),
+            Self::MedicalAdvisor => "Medical Advisor".to_string(),
+            Self::ContentCreator => "Content Creator".to_string(),
+            Self::VideoEditor => "Video Editor".to_string(),
+            Self::GraphicDesigner => "Graphic Designer".to_string(),
+            Self::MusicProducer => "Music Producer".to_string(),
+            Self::DevOpsEngineer => "DevOps Engineer".to_string(),
+            Self::DataAnalyst => "Data Analyst".to_string(),
+            Self::SecurityAnalyst => "Security Analyst".to_string(),
+            Self::CloudArchitect => "Cloud Architect".to_string(),
+            Self::ResearchAnalyst => "Research Analyst".to_string(),
+            Self::EducationAdvisor => "Education Advisor".to_string(),
+            Self::LanguageTutor => "Language Tutor".to_string(),
+            Self::SkillCoach => "Skill Coach".to_string(),
+            Self::TarotReader => "Tarot Reader".to_string(),
+            Self::Astrologer => "Astrologer".to_string(),
+            Self::MeditationGuide => "Meditation Guide".to_string(),
+            Self::LifeCoach => "Life Coach".to_string(),
+            Self::KitchenManager => "Kitchen Manager".to_string(),
+            Self::HomeAutomation => "Home Automation".to_string(),
+            Self::MaintenanceScheduler => "Maintenance Scheduler".to_string(),
+            Self::QuantumComputing => "Quantum Computing".to_string(),
+            Self::EmergencyResponse => "Emergency Response".to_string(),
+        }
+    }
+
+    pub fn get_default_size(&self) -> (u32, u32) {
+        match self {
+            Self::Orchestrator => (1400, 900),
+            Self::Memory => (1200, 800),
+            Self::TradingOffice | Self::CryptoOffice => (1600, 900),
+            Self::QuantumComputing => (1500, 850),
+            _ => (1024, 768),
+        }
+    }
+
+    pub fn get_url(&self) -> String {
+        let base_url = "http://localhost:1420";
+        match self {
+            Self::Orchestrator => format!("{}/", base_url),
+            Self::Memory => format!("{}/memory", base_url),
+            Self::Security => format!("{}/security", base_url),
+            _ => format!("{}/office/{:?}", base_url, self).to_lowercase(),
+        }
+    }
+}
+
+/// Manages all Unity office windows
+pub struct WindowManager {
+    windows: Arc<RwLock<HashMap<String, OfficeWindow>>>,
+    presences: Arc<RwLock<HashMap<String, Presence>>>,
+    app_handle: tauri::AppHandle,
+    node_registry: Arc<NodeRegistry>,
+    // Offices that live on a remote node rather than as a local window, keyed by office type.
+    remote_offices: Arc<RwLock<HashMap<OfficeType, String>>>,
+    event_handler: Arc<RwLock<Arc<dyn OfficeEventHandler>>>,
+    // Prefix that marks a text payload as a routable command, e.g. "!" for "!trade buy BTC".
+    command_prefix: String,
+    // Single source of truth for windows; the in-memory map above is a hydrated cache of this.
+    store: Arc<Store>,
+    // Oneshot senders awaiting an office's reply to a correlated `SendMessage`, keyed by
+    // correlation id; resolved by `spawn_response_listener` when a `workflow_response` event
+    // arrives.
+    pending_responses: Arc<RwLock<HashMap<String, oneshot::Sender<serde_json::Value>>>>,
+    // The paired receivers, held separately so `await_response` can take ownership of one
+    // without racing `spawn_response_listener`'s access to `pending_responses`.
+    pending_receivers: Arc<RwLock<HashMap<String, oneshot::Receiver<serde_json::Value>>>>,
+    audio: Arc<Audio>,
+}
+
+impl WindowManager {
+    /// Builds a manager with an empty in-memory map, for tests or callers that hydrate separately.
+    pub fn new(
+        app_handle: tauri::AppHandle,
+        node_registry: Arc<NodeRegistry>,
+        store: Arc<Store>,
+        audio: Arc<Audio>,
+    ) -> Self {
+        let manager = Self {
+            windows: Arc::new(RwLock::new(HashMap::new())),
+            presences: Arc::new(RwLock::new(HashMap::new())),
+            app_handle,
+            node_registry,
+            remote_offices: Arc::new(RwLock::new(HashMap::new())),
+            event_handler: Arc::new(RwLock::new(Arc::new(LoggingEventHandler))),
+            command_prefix: "!".to_string(),
+            store,
+            pending_responses: Arc::new(RwLock::new(HashMap::new())),
+            pending_receivers: Arc::new(RwLock::new(HashMap::new())),
+            audio,
+        };
+
+        manager.spawn_presence_heartbeat();
+        manager.spawn_response_listener();
+        manager
+    }
+
+    /// Listens globally for `workflow_response` events (`{correlation_id, value}`) and resolves
+    /// the matching oneshot registered by `send_correlated_message`, if still pending.
+    fn spawn_response_listener(&self) {
+        let pending_responses = self.pending_responses.clone();
+        self.app_handle.listen_global("workflow_response", move |event| {
+            let pending_responses = pending_responses.clone();
+            let reply: Option<WorkflowResponse> = event.payload().and_then(|p| serde_json::from_str(p).ok());
+
+            if let Some(reply) = reply {
+                tokio::spawn(async move {
+                    if let Some(tx) = pending_responses.write().await.remove(&reply.correlation_id) {
+                        let _ = tx.send(reply.value);
+                    }
+                });
+            }
+        });
+    }
+
+    /// Builds a manager and re-opens every office window that was still active when the app last
+    /// shut down, so a restart doesn't silently lose the user's arrangement.
+    pub async fn new_with_hydration(
+        app_handle: tauri::AppHandle,
+        node_registry: Arc<NodeRegistry>,
+        store: Arc<Store>,
+        audio: Arc<Audio>,
+    ) -> Result<Self, String> {
+        let manager = Self::new(app_handle, node_registry, store, audio);
+
+        for window in manager.store.load_windows().await? {
+            manager
+                .create_office_window(window.office_type, window.memory_consent, Some(window.shared_memory_ttl))
+                .await?;
+            // The re-opened window is saved under a fresh id; drop the old record so it isn't
+            // re-opened a second time on the next launch.
+            manager.store.remove_window(&window.id).await?;
+        }
+
+        Ok(manager)
+    }
+
+    /// Marks an office type as hosted on a connected remote node, so future
+    /// `create_office_window`/`send_to_office` calls route to it instead of a local window.
+    pub async fn register_remote_office(&self, office_type: OfficeType, node_id: String) {
+        self.remote_offices.write().await.insert(office_type, node_id);
+    }
+
+    /// Registers the handler that office IPC events are dispatched to, replacing whatever
+    /// was previously registered (the default is `LoggingEventHandler`).
+    pub async fn set_event_handler(&self, handler: Arc<dyn OfficeEventHandler>) {
+        *self.event_handler.write().await = handler;
+    }
+
+    /// Periodically drops presences that haven't been refreshed in a while, so a window that
+    /// crashed without closing cleanly doesn't linger in everyone else's view forever.
+    fn spawn_presence_heartbeat(&self) {
+        let presences = self.presences.clone();
+        let app_handle = self.app_handle.clone();
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(std::time::Duration::from_secs(
+                PRESENCE_HEARTBEAT_INTERVAL_SECS,
+            ));
+            loop {
+                interval.tick().await;
+
+                let stale: Vec<String> = {
+                    let presences = presences.read().await;
+                    presences
+                        .values()
+                        .filter(|p| p.is_stale())
+                        .map(|p| p.window_id.clone())
+                        .collect()
+                };
+
+                if stale.is_empty() {
+                    continue;
+                }
+
+                let mut presences = presences.write().await;
+                for window_id in stale {
+                    presences.remove(&window_id);
+                    let _ = app_handle.emit_all(PRESENCE_UPDATE_EVENT, &PresenceRemoved { window_id });
+                }
+            }
+        });
+    }
+
+    /// Update (or create) the live presence for a window and fan the delta out to other offices.
+    pub async fn update_presence(&self, presence: Presence) -> Result<(), String> {
+        let window_id = presence.window_id.clone();
+        self.presences.write().await.insert(window_id, presence.clone());
+
+        self.app_handle
+            .emit_all(PRESENCE_UPDATE_EVENT, &presence)
+            .map_err(|e| format!("Failed to broadcast presence: {}", e))
+    }
+
+    /// Get all tracked presences (used to hydrate a newly opened office's view of who else is active).
+    pub async fn get_presences(&self) -> Vec<Presence> {
+        self.presences.read().await.values().cloned().collect()
+    }
+
+    async fn evict_presence(&self, window_id: &str) {
+        let removed = self.presences.write().await.remove(window_id).is_some();
+        if removed {
+            let _ = self.app_handle.emit_all(
+                PRESENCE_UPDATE_EVENT,
+                &PresenceRemoved { window_id: window_id.to_string() },
+            );
+        }
+    }
+
+    /// Create a new office window
+    pub async fn create_office_window(
+        &self,
+        office_type: OfficeType,
+        memory_consent: bool,
+        shared_memory_ttl: Option<u64>,
+    ) -> Result<String, String> {
+        if let Some(node_id) = self.remote_offices.read().await.get(&office_type).cloned() {
+            let node = self
+                .node_registry
+                .get(&node_id)
+                .await
+                .ok_or_else(|| format!("Office is routed to disconnected node {}", node_id))?;
+            return node.open_office(&office_type, memory_consent).await;
+        }
+
+        let window_id = format!("office_{}", Uuid::new_v4());
+        let title = format!("Unity — {}", office_type.to_string());
+        let size = office_type.get_default_size();
+        let url = office_type.get_url();
+
+        // Create the actual Tauri window
+        let window = WindowBuilder::new(
+            &self.app_handle,
+            &window_id,
+            WindowUrl::App(url.into()),
+        )
+        .title(&title)
+        .inner_size(size.0 as f64, size.1 as f64)
+        .resizable(true)
+        .center()
+        .build()
+        .map_err(|e| format!("Failed to create window: {}", e))?;
+
+        // Store window metadata
+        let office_window = OfficeWindow {
+            id: window_id.clone(),
+            office_type: office_type.clone(),
+            title,
+            position: None,
+            size,
+            memory_consent,
+            shared_memory_ttl: shared_memory_ttl.unwrap_or(3600), // Default 1 hour TTL
+            muted: false,
+        };
+
+        self.store.save_window(&office_window).await?;
+
+        if !office_window.muted {
+            self.audio.play_cue(Cue::OfficeOpened);
+        }
+
+        let mut windows = self.windows.write().await;
+        windows.insert(window_id.clone(), office_window);
+        drop(windows);
+
+        // Set up IPC handlers for this window
+        self.setup_window_ipc(&window, office_type).await?;
+
+        Ok(window_id)
+    }
+
+    /// Set up inter-process communication for a window
+    async fn setup_window_ipc(
+        &self,
+        window: &Window,
+        office_type: OfficeType,
+    ) -> Result<(), String> {
+        // Listen for memory sharing requests
+        let handler = self.event_handler.clone();
+        let office_type_clone = office_type.clone();
+        window.listen("request_memory_access", move |event| {
+            let handler = handler.clone();
+            let office_type = office_type_clone.clone();
+            let payload = event.payload().map(|p| p.to_string());
+            tokio::spawn(async move {
+                let handler = handler.read().await.clone();
+                handler.on_memory_access_request(&office_type, payload.as_deref()).await;
+            });
+        });
+
+        // Listen for inter-office messages: dispatch to the registered handler, and additionally
+        // route text payloads beginning with `command_prefix` to the matching office.
+        let handler = self.event_handler.clone();
+        let office_type_clone = office_type.clone();
+        let windows = self.windows.clone();
+        let app_handle = self.app_handle.clone();
+        let prefix = self.command_prefix.clone();
+        let window_clone = window.clone();
+        let audio = self.audio.clone();
+        window.listen("inter_office_message", move |event| {
+            let handler = handler.clone();
+            let office_type = office_type_clone.clone();
+            let payload = event.payload().map(|p| p.to_string());
+            let windows = windows.clone();
+            let app_handle = app_handle.clone();
+            let prefix = prefix.clone();
+            let window_clone = window_clone.clone();
+            let audio = audio.clone();
+
+            tokio::spawn(async move {
+                let handler_guard = handler.read().await.clone();
+                handler_guard.on_inter_office_message(&office_type, payload.as_deref()).await;
+
+                let muted = windows.read().await.get(window_clone.label()).map(|w| w.muted).unwrap_or(false);
+                if !muted {
+                    audio.play_cue(Cue::InterOfficeMessage);
+                }
+
+                if let Some(text) = payload.as_deref() {
+                    let unquoted = text.trim_matches('"');
+                    if let Some(command) = event_handler::parse_command(&prefix, unquoted) {
+                        dispatch_command(&windows, &app_handle, &window_clone, command).await;
+                    }
+                }
+            });
+        });
+
+        // Listen for structured messages relayed by `send_to_office`, so
+        // `OfficeEventHandler::on_office_message` (unlike the other two hooks) isn't dead code.
+        let handler = self.event_handler.clone();
+        let office_type_clone = office_type.clone();
+        window.listen("office_message", move |event| {
+            let handler = handler.clone();
+            let office_type = office_type_clone.clone();
+            let message: serde_json::Value = event
+                .payload()
+                .and_then(|p| serde_json::from_str(p).ok())
+                .unwrap_or(serde_json::Value::Null);
+            tokio::spawn(async move {
+                let handler = handler.read().await.clone();
+                handler.on_office_message(&office_type, &message).await;
+            });
+        });
+
+        Ok(())
+    }
+
+        /// Find the window id(s) hosting `office_type`, used by the free-standing
+    /// `dispatch_command` helper which runs outside of a `&WindowManager` borrow.
+    async fn windows_for(
+        windows: &Arc<RwLock<HashMap<String, OfficeWindow>>>,
+        office_type: &OfficeType,
+    ) -> Vec<String> {
+        windows
+            .read()
+            .await
+            .values()
+            .filter(|w| w.office_type == *office_type)
+            .map(|w| w.id.clone())
+            .collect()
+    }
+
+    /// Close an office window
+    pub async fn close_office_window(&self, window_id: &str) -> Result<(), String> {
+        // Remove from tracking
+        let mut windows = self.windows.write().await;
+        windows.remove(window_id);
+        drop(windows);
+
+        self.store.remove_window(window_id).await?;
+        self.evict_presence(window_id).await;
+
+        // Close the actual window
+        if let Some(window) = self.app_handle.get_window(window_id) {
+            window.close().map_err(|e| format!("Failed to close window: {}", e))?;
+        }
+
+        Ok(())
+    }
+
+    /// Get all active office windows
+    pub async fn get_active_offices(&self) -> Vec<OfficeWindow> {
+        let windows = self.windows.read().await;
+        windows.values().cloned().collect()
+    }
+
+    /// Get a single office window by id
+    pub async fn get_office(&self, window_id: &str) -> Option<OfficeWindow> {
+        let windows = self.windows.read().await;
+        windows.get(window_id).cloned()
+    }
+
+    /// Sends `message` to `office_type` with a generated correlation id attached, and registers
+    /// a oneshot that `spawn_response_listener` will resolve once the office replies via a
+    /// `workflow_response` event carrying that id. Returns the id so the caller can await it.
+    async fn send_correlated_message(
+        &self,
+        office_type: &OfficeType,
+        message: serde_json::Value,
+    ) -> Result<String, String> {
+        let correlation_id = Uuid::new_v4().to_string();
+        let (tx, rx) = oneshot::channel();
+        self.pending_responses.write().await.insert(correlation_id.clone(), tx);
+
+        let envelope = serde_json::json!({ "correlation_id": correlation_id, "payload": message });
+        if let Err(e) = self.send_to_office(office_type, envelope).await {
+            self.pending_responses.write().await.remove(&correlation_id);
+            return Err(e);
+        }
+
+        // Stash the receiver so `await_response` can pick it back up by id.
+        self.pending_receivers.write().await.insert(correlation_id.clone(), rx);
+        Ok(correlation_id)
+    }
+
+    /// Waits up to `timeout` for the office to reply to `correlation_id`, returning `None` on
+    /// timeout or if no such correlation is outstanding.
+    async fn await_response(&self, correlation_id: &str, timeout: std::time::Duration) -> Option<serde_json::Value> {
+        let rx = self.pending_receivers.write().await.remove(correlation_id)?;
+        match tokio::time::timeout(timeout, rx).await {
+            Ok(Ok(value)) => Some(value),
+            _ => {
+                self.pending_responses.write().await.remove(correlation_id);
+                None
+            }
+        }
+    }
+
+    /// Snapshots the currently active offices under `name` so they can be restored later.
+    pub async fn save_layout(&self, name: &str) -> Result<(), String> {
+        let offices = self.get_active_offices().await;
+        self.store.save_layout(name, &offices).await
+    }
+
+    /// Re-opens every office window in the layout snapshotted under `name`.
+    pub async fn restore_layout(&self, name: &str) -> Result<(), String> {
+        for window in self.store.restore_layout(name).await? {
+            self.create_office_window(window.office_type, window.memory_consent, Some(window.shared_memory_ttl))
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Emit an event to every consenting office window except `exclude_window_id`
+    pub async fn emit_to_consenting(
+        &self,
+        exclude_window_id: &str,
+        event: &str,
+        payload: &impl Serialize,
+    ) -> Result<(), String> {
+        let windows = self.windows.read().await;
+
+        for (window_id, office_window) in windows.iter() {
+            if window_id == exclude_window_id || !office_window.memory_consent {
+                continue;
+            }
+            if let Some(window) = self.app_handle.get_window(window_id) {
+                window
+                    .emit(event, payload)
+                    .map_err(|e| format!("Failed to emit {}: {}", event, e))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Send a message to a specific office
+    pub async fn send_to_office(
+        &self,
+        office_type: &OfficeType,
+        message: serde_json::Value,
+    ) -> Result<(), String> {
+        if let Some(node_id) = self.remote_offices.read().await.get(office_type).cloned() {
+            let node = self
+                .node_registry
+                .get(&node_id)
+                .await
+                .ok_or_else(|| format!("Office is routed to disconnected node {}", node_id))?;
+            return node.send_message(office_type, &message).await;
+        }
+
+        let windows = self.windows.read().await;
+
+        for (window_id, office_window) in windows.iter() {
+            if office_window.office_type == *office_type {
+                if let Some(window) = self.app_handle.get_window(window_id) {
+                    window
+                        .emit("office_message", &message)
+                        .map_err(|e| format!("Failed to send message: {}", e))?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Broadcast a message to all offices
+    pub async fn broadcast_to_all(&self, message: serde_json::Value) -> Result<(), String> {
+        let windows = self.windows.read().await;
+
+        for window_id in windows.keys() {
+            if let Some(window) = self.app_handle.get_window(window_id) {
+                window
+                    .emit("system_broadcast", &message)
+                    .map_err(|e| format!("Failed to broadcast: {}", e))?;
+            }
+        }
+        drop(windows);
+
+        // Also relay to every connected remote node, so federated offices see the broadcast too.
+        for node_id in self.node_registry.connected_ids().await {
+            if let Some(node) = self.node_registry.get(&node_id).await {
+                node.broadcast(&message).await?;
+            }
+        }
+
+        // A system-wide broadcast is the closest analog to an emergency alert - play the 40Hz
+        // entrainment pulse regardless of any single office's mute flag.
+        self.audio.play_cue(Cue::EmergencyBroadcast);
+
+        Ok(())
+    }
+
+    /// Update memory sharing consent for an office
+    pub async fn update_memory_consent(
+        &self,
+        window_id: &str,
+        consent: bool,
+    ) -> Result<(), String> {
+        let mut windows = self.windows.write().await;
+
+        if let Some(office_window) = windows.get_mut(window_id) {
+            office_window.memory_consent = consent;
+            self.store.save_window(office_window).await?;
+
+            // Notify the window of the consent update
+            if let Some(window) = self.app_handle.get_window(window_id) {
+                window
+                    .emit("memory_consent_updated", &consent)
+                    .map_err(|e| format!("Failed to update consent: {}", e))?;
+            }
+
+            Ok(())
+        } else {
+            Err("Window not found".to_string())
+        }
+    }
+
+    /// Mute or unmute an office's audio cues (window opened, inter-office message).
+    pub async fn set_office_muted(&self, window_id: &str, muted: bool) -> Result<(), String> {
+        let office_window = {
+            let mut windows = self.windows.write().await;
+            let office_window = windows.get_mut(window_id).ok_or_else(|| "Window not found".to_string())?;
+            office_window.muted = muted;
+            office_window.clone()
+        };
+        self.store.save_window(&office_window).await
+    }
+
+    /// Update TTL for shared memory
+    pub async fn update_memory_ttl(&self, window_id: &str, ttl: u64) -> Result<(), String> {
+        let mut windows = self.windows.write().await;
+
+        if let Some(office_window) = windows.get_mut(window_id) {
+            office_window.shared_memory_ttl = ttl;
+            self.store.save_window(office_window).await
+        } else {
+            Err("Window not found".to_string())
+        }
+    }
+
+    /// Orchestrate a multi-office workflow
+    pub async fn orchestrate_workflow(
+        &self,
+        workflow: WorkflowDefinition,
+    ) -> Result<String, String> {
+        let workflow_id = Uuid::new_v4().to_string();
+        self.store
+            .start_workflow_run(&workflow_id, &workflow.name, workflow.steps.len())
+            .await?;
+
+        // Execute workflow steps, recording each one's status so a restart can tell how far a
+        // long-running workflow got. `ctx` threads the last correlation id and captured response
+        // through the run so `WaitForResponse`/`Branch` can see what a prior `SendMessage` got
+        // back; nested steps inside a `Branch` share the run's store entry for their parent step
+        // rather than getting their own, since the top-level step count is fixed at launch.
+        let mut ctx = WorkflowContext::default();
+        for (index, step) in workflow.steps.into_iter().enumerate() {
+            self.store
+                .update_step_status(&workflow_id, index, store::StepStatus::Running)
+                .await?;
+
+            let outcome = self.execute_step(step, &mut ctx).await;
+
+            let status = match &outcome {
+                Ok(()) => store::StepStatus::Done,
+                Err(e) => store::StepStatus::Failed(e.clone()),
+            };
+            self.store.update_step_status(&workflow_id, index, status).await?;
+            outcome?;
+        }
+
+        self.audio.play_cue(Cue::WorkflowCompleted);
+        Ok(workflow_id)
+    }
+
+    /// Executes a single workflow step. Boxed because `Branch` recurses into its own
+    /// `then_steps`/`else_steps`, and async fns can't recurse without indirection.
+    fn execute_step<'a>(
+        &'a self,
+        step: WorkflowStep,
+        ctx: &'a mut WorkflowContext,
+    ) -> BoxFuture<'a, Result<(), String>> {
+        Box::pin(async move {
+            match step.action {
+                WorkflowAction::OpenOffice(office_type) => {
+                    self.create_office_window(office_type, true, None).await.map(|_| ())
+                }
+                WorkflowAction::SendMessage(office_type, message) => {
+                    let correlation_id = self.send_correlated_message(&office_type, message).await?;
+                    ctx.last_correlation_id = Some(correlation_id);
+                    Ok(())
+                }
+                WorkflowAction::WaitForResponse(timeout) => {
+                    let duration = std::time::Duration::from_secs(timeout);
+                    ctx.last_response = match ctx.last_correlation_id.take() {
+                        Some(correlation_id) => self.await_response(&correlation_id, duration).await,
+                        None => {
+                            // No preceding correlated SendMessage to wait on - fall back to a
+                            // plain timeout, matching the step's original meaning.
+                            tokio::time::sleep(duration).await;
+                            None
+                        }
+                    };
+                    Ok(())
+                }
+                WorkflowAction::CloseOffice(window_id) => self.close_office_window(&window_id).await,
+                WorkflowAction::Branch { condition, then_steps, else_steps } => {
+                    let matched = ctx.last_response.as_ref() == Some(&condition);
+                    let steps = if matched { then_steps } else { else_steps };
+                    for step in steps {
+                        self.execute_step(step, ctx).await?;
+                    }
+                    Ok(())
+                }
+            }
+        })
+    }
+}
+
+/// Carries the last correlation id and captured office response through a single
+/// `orchestrate_workflow` run, so a `WaitForResponse`/`Branch` step can see what the most recent
+/// correlated `SendMessage` produced.
+#[derive(Default)]
+struct WorkflowContext {
+    last_correlation_id: Option<String>,
+    last_response: Option<serde_json::Value>,
+}
+
+/// Payload of the `workflow_response` event an office emits to resolve a pending `SendMessage`.
+#[derive(Debug, Deserialize)]
+struct WorkflowResponse {
+    correlation_id: String,
+    value: serde_json::Value,
+}
+
+/// Routes a parsed `OfficeCommand` to the office it targets and emits a structured
+/// `OfficeCommandReply` back to the window that issued it. Lives outside `WindowManager` because
+/// it's invoked from a `window.listen` closure, which can't hold a `&WindowManager` borrow.
+async fn dispatch_command(
+    windows: &Arc<RwLock<HashMap<String, OfficeWindow>>>,
+    app_handle: &tauri::AppHandle,
+    origin: &Window,
+    command: event_handler::OfficeCommand,
+) {
+    let reply = match event_handler::route_command(&command) {
+        Some(office_type) => {
+            let targets = WindowManager::windows_for(windows, &office_type).await;
+            if targets.is_empty() {
+                OfficeCommandReply {
+                    command: command.name.clone(),
+                    ok: false,
+                    message: format!("No office of type {:?} is open", office_type),
+                }
+            } else {
+                let payload = serde_json::json!({ "name": command.name, "args": command.args });
+                let mut failures = Vec::new();
+                for window_id in &targets {
+                    if let Some(window) = app_handle.get_window(window_id) {
+                        if let Err(e) = window.emit("office_command", &payload) {
+                            failures.push(e.to_string());
+                        }
+                    }
+                }
+                if failures.is_empty() {
+                    OfficeCommandReply { command: command.name.clone(), ok: true, message: "Delivered".to_string() }
+                } else {
+                    OfficeCommandReply {
+                        command: command.name.clone(),
+                        ok: false,
+                        message: format!("Failed to deliver to some windows: {}", failures.join(", ")),
+                    }
+                }
+            }
+        }
+        None => OfficeCommandReply {
+            command: command.name.clone(),
+            ok: false,
+            message: format!("Unknown command: {}", command.name),
+        },
+    };
+
+    let _ = origin.emit("office_command_reply", &reply);
+}
+
+/// Workflow definition for multi-office orchestration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkflowDefinition {
+    pub name: String,
+    pub steps: Vec<WorkflowStep>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkflowStep {
+    pub action: WorkflowAction,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum WorkflowAction {
+    OpenOffice(OfficeType),
+    SendMessage(OfficeType, serde_json::Value),
+    WaitForResponse(u64),
+    CloseOffice(String),
+    /// Branches on whether the response captured by the most recent `WaitForResponse` equals
+    /// `condition`, turning the step list into a small DAG instead of a straight line.
+    Branch {
+        condition: serde_json::Value,
+        then_steps: Vec<WorkflowStep>,
+        else_steps: Vec<WorkflowStep>,
+    },
+}
+
+/// Tauri commands for window management
+#[tauri::command]
+pub async fn create_office(
+    state: tauri::State<'_, Arc<RwLock<WindowManager>>>,
+    office_type: String,
+    memory_consent: bool,
+) -> Result<String, String> {
+    let office_type = serde_json::from_str::<OfficeType>(&format!("\"{}\"", office_type))
+        .map_err(|e| format!("Invalid office type: {}", e))?;
+
+    let manager = state.read().await;
+    manager.create_office_window(office_type, memory_consent, None).await
+}
+
+#[tauri::command]
+pub async fn close_office(
+    state: tauri::State<'_, Arc<RwLock<WindowManager>>>,
+    window_id: String,
+) -> Result<(), String> {
+    let manager = state.read().await;
+    manager.close_office_window(&window_id).await
+}
+
+#[tauri::command]
+pub async fn get_offices(
+    state: tauri::State<'_, Arc<RwLock<WindowManager>>>,
+) -> Result<Vec<OfficeWindow>, String> {
+    let manager = state.read().await;
+    Ok(manager.get_active_offices().await)
+}
+
+#[tauri::command]
+pub async fn send_office_message(
+    state: tauri::State<'_, Arc<RwLock<WindowManager>>>,
+    office_type: String,
+    message: serde_json::Value,
+) -> Result<(), String> {
+    let office_type = serde_json::from_str::<OfficeType>(&format!("\"{}\"", office_type))
+        .map_err(|e| format!("Invalid office type: {}", e))?;
+
+    let manager = state.read().await;
+    manager.send_to_office(&office_type, message).await
+}
+
+#[tauri::command]
+pub async fn broadcast_message(
+    state: tauri::State<'_, Arc<RwLock<WindowManager>>>,
+    message: serde_json::Value,
+) -> Result<(), String> {
+    let manager = state.read().await;
+    manager.broadcast_to_all(message).await
+}
+
+#[tauri::command]
+pub async fn update_presence(
+    state: tauri::State<'_, Arc<RwLock<WindowManager>>>,
+    office_type: String,
+    window_id: String,
+    cursor: Option<(u32, u32)>,
+    buffer: Option<String>,
+) -> Result<(), String> {
+    let office_type = serde_json::from_str::<OfficeType>(&format!("\"{}\"", office_type))
+        .map_err(|e| format!("Invalid office type: {}", e))?;
+
+    let manager = state.read().await;
+    manager
+        .update_presence(Presence {
+            office_type,
+            window_id,
+            cursor,
+            buffer,
+            updated_at: presence::now(),
+        })
+        .await
+}
+
+#[tauri::command]
+pub async fn register_remote_office(
+    state: tauri::State<'_, Arc<RwLock<WindowManager>>>,
+    office_type: String,
+    node_id: String,
+) -> Result<(), String> {
+    let office_type = serde_json::from_str::<OfficeType>(&format!("\"{}\"", office_type))
+        .map_err(|e| format!("Invalid office type: {}", e))?;
+
+    let manager = state.read().await;
+    manager.register_remote_office(office_type, node_id).await;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_presences(
+    state: tauri::State<'_, Arc<RwLock<WindowManager>>>,
+) -> Result<Vec<Presence>, String> {
+    let manager = state.read().await;
+    Ok(manager.get_presences().await)
+}
+
+#[tauri::command]
+pub async fn save_layout(
+    state: tauri::State<'_, Arc<RwLock<WindowManager>>>,
+    name: String,
+) -> Result<(), String> {
+    let manager = state.read().await;
+    manager.save_layout(&name).await
+}
+
+#[tauri::command]
+pub async fn restore_layout(
+    state: tauri::State<'_, Arc<RwLock<WindowManager>>>,
+    name: String,
+) -> Result<(), String> {
+    let manager = state.read().await;
+    manager.restore_layout(&name).await
+}
+
+#[tauri::command]
+pub async fn set_office_muted(
+    state: tauri::State<'_, Arc<RwLock<WindowManager>>>,
+    window_id: String,
+    muted: bool,
+) -> Result<(), String> {
+    let manager = state.read().await;
+    manager.set_office_muted(&window_id, muted).await
+}
+
+#[tauri::command]
+pub async fn stop_all_audio(state: tauri::State<'_, Arc<RwLock<WindowManager>>>) -> Result<(), String> {
+    let manager = state.read().await;
+    manager.audio.stop_all();
+    Ok(())
+}
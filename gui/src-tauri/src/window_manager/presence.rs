@@ -0,0 +1,35 @@
+// Live presence/cursor broadcasting between offices, mirroring the cursor-worker pattern used in
+// collaborative editors: each active window's cursor position and buffer label are fanned out to
+// every other office so multi-office workflows are observable, with automatic eviction on close
+// and a heartbeat that drops anything that's gone stale. Focus/idle state is not tracked.
+
+use serde::{Deserialize, Serialize};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub const PRESENCE_UPDATE_EVENT: &str = "presence_update";
+pub const PRESENCE_HEARTBEAT_INTERVAL_SECS: u64 = 10;
+pub const PRESENCE_STALE_AFTER_SECS: f64 = 30.0;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Presence {
+    pub office_type: crate::window_manager::OfficeType,
+    pub window_id: String,
+    pub cursor: Option<(u32, u32)>,
+    pub buffer: Option<String>,
+    pub updated_at: f64,
+}
+
+impl Presence {
+    pub fn is_stale(&self) -> bool {
+        now() - self.updated_at > PRESENCE_STALE_AFTER_SECS
+    }
+}
+
+pub fn now() -> f64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs_f64()
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PresenceRemoved {
+    pub window_id: String,
+}
@@ -0,0 +1,195 @@
+// Operational-transform backed shared memory for consenting offices. Each shared document is a
+// plain-text sequence with a monotonically increasing revision number and an op log; concurrent
+// edits from different offices are rebased against one another instead of last-writer-wins.
+
+use operational_transform::OperationSeq;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
+
+use crate::window_manager::WindowManager;
+
+struct SharedDoc {
+    content: String,
+    revision: u64,
+    op_log: Vec<OperationSeq>,
+    ttl: Duration,
+    last_written_at: Instant,
+}
+
+impl SharedDoc {
+    fn new(ttl: Duration) -> Self {
+        Self {
+            content: String::new(),
+            revision: 0,
+            op_log: Vec::new(),
+            ttl,
+            last_written_at: Instant::now(),
+        }
+    }
+
+    fn is_expired(&self) -> bool {
+        self.last_written_at.elapsed() >= self.ttl
+    }
+
+    fn expire(&mut self) {
+        self.content.clear();
+        self.revision = 0;
+        self.op_log.clear();
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RebasedOp {
+    pub doc_id: String,
+    pub origin_window: String,
+    pub op: OperationSeq,
+    pub revision: u64,
+}
+
+/// Tracks one shared document per `doc_id` and rebases incoming ops via operational transform.
+pub struct SharedMemory {
+    docs: Arc<RwLock<HashMap<String, SharedDoc>>>,
+}
+
+impl SharedMemory {
+    pub fn new() -> Self {
+        Self {
+            docs: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Submits an op authored against `base_revision`. If the server has advanced past that
+    /// revision, the op is transformed against each intervening op before being applied, per the
+    /// standard `transform(a, b) -> (a', b')` rule so all clients converge on the same content.
+    pub async fn submit_op(
+        &self,
+        doc_id: &str,
+        window_id: &str,
+        base_revision: u64,
+        op: OperationSeq,
+        ttl: Duration,
+        manager: &WindowManager,
+    ) -> Result<RebasedOp, String> {
+        let mut docs = self.docs.write().await;
+        let doc = docs.entry(doc_id.to_string()).or_insert_with(|| SharedDoc::new(ttl));
+
+        if doc.is_expired() {
+            doc.expire();
+        }
+        doc.ttl = ttl;
+
+        if base_revision > doc.revision {
+            return Err(format!(
+                "base_revision {} is ahead of server revision {}",
+                base_revision, doc.revision
+            ));
+        }
+
+        let mut rebased = op;
+        for prior_op in &doc.op_log[base_revision as usize..] {
+            let (transformed, _) = rebased
+                .transform(prior_op)
+                .map_err(|e| format!("Failed to transform op: {:?}", e))?;
+            rebased = transformed;
+        }
+
+        doc.content = rebased
+            .apply(&doc.content)
+            .map_err(|e| format!("Failed to apply op: {:?}", e))?;
+        doc.op_log.push(rebased.clone());
+        doc.revision += 1;
+        doc.last_written_at = Instant::now();
+
+        let rebased_op = RebasedOp {
+            doc_id: doc_id.to_string(),
+            origin_window: window_id.to_string(),
+            op: rebased,
+            revision: doc.revision,
+        };
+
+        manager
+            .emit_to_consenting(window_id, "shared_memory_op", &rebased_op)
+            .await?;
+
+        Ok(rebased_op)
+    }
+
+    /// Returns the current content and revision for a doc, or `None` if it doesn't exist or its
+    /// TTL has lapsed (in which case it is cleared as a side effect).
+    pub async fn get_doc(&self, doc_id: &str) -> Option<(String, u64)> {
+        let mut docs = self.docs.write().await;
+        let doc = docs.get_mut(doc_id)?;
+
+        if doc.is_expired() {
+            doc.expire();
+            return None;
+        }
+
+        Some((doc.content.clone(), doc.revision))
+    }
+}
+
+#[tauri::command]
+pub async fn submit_shared_op(
+    manager_state: tauri::State<'_, Arc<RwLock<WindowManager>>>,
+    shared_state: tauri::State<'_, Arc<SharedMemory>>,
+    doc_id: String,
+    window_id: String,
+    base_revision: u64,
+    op: OperationSeq,
+) -> Result<RebasedOp, String> {
+    let manager = manager_state.read().await;
+    let ttl = manager
+        .get_office(&window_id)
+        .await
+        .map(|office| Duration::from_secs(office.shared_memory_ttl))
+        .unwrap_or(Duration::from_secs(3600));
+
+    shared_state
+        .submit_op(&doc_id, &window_id, base_revision, op, ttl, &manager)
+        .await
+}
+
+#[tauri::command]
+pub async fn get_shared_doc(
+    shared_state: tauri::State<'_, Arc<SharedMemory>>,
+    doc_id: String,
+) -> Result<Option<(String, u64)>, String> {
+    Ok(shared_state.get_doc(&doc_id).await)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Pins the OT convergence invariant `submit_op` relies on: two concurrent edits against the
+    // same base revision must land on the same content regardless of which one the server applies
+    // first, as long as the other is transformed against it before being applied.
+    #[test]
+    fn concurrent_ops_converge_regardless_of_apply_order() {
+        let base = "hello world";
+
+        let mut a = OperationSeq::default();
+        a.retain(6);
+        a.insert("there ");
+        a.retain(5);
+
+        let mut b = OperationSeq::default();
+        b.retain(11);
+        b.insert("!");
+
+        let (a_prime, b_prime) = a.transform(&b).expect("transform should succeed");
+
+        let applied_a_then_b_prime = b_prime
+            .apply(&a.apply(base).expect("apply a"))
+            .expect("apply b' after a");
+        let applied_b_then_a_prime = a_prime
+            .apply(&b.apply(base).expect("apply b"))
+            .expect("apply a' after b");
+
+        assert_eq!(applied_a_then_b_prime, applied_b_then_a_prime);
+    }
+}
@@ -0,0 +1,206 @@
+// Durable state for WindowManager: office windows and workflow runs are persisted to a SQLite
+// database so they survive an app restart, instead of living only in the in-memory `HashMap`s
+// that disappear on shutdown. Rows store the domain structs as JSON blobs (matching the rest of
+// the crate's preference for serde_json over hand-rolled column mapping) rather than normalizing
+// every field into its own column.
+
+use serde::{Deserialize, Serialize};
+use sqlx::sqlite::{SqlitePool, SqliteRow};
+use sqlx::Row;
+
+use super::OfficeWindow;
+
+/// Per-step status recorded for a `WorkflowDefinition` run, so a crashed or long-running
+/// workflow's progress can be inspected after a restart. Runs are not resumed from it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum StepStatus {
+    Pending,
+    Running,
+    Done,
+    Failed(String),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkflowRun {
+    pub id: String,
+    pub name: String,
+    pub steps: Vec<StepStatus>,
+    pub started_at: f64,
+    pub finished_at: Option<f64>,
+}
+
+fn now() -> f64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs_f64()
+}
+
+/// SQLite-backed store for `OfficeWindow`s, named layouts, and workflow runs. `WindowManager`
+/// treats this as the single source of truth, hydrating its in-memory map from it at startup
+/// and writing through on every change.
+pub struct Store {
+    pool: SqlitePool,
+}
+
+impl Store {
+    /// Opens (creating if absent) the SQLite database at `path` and runs migrations.
+    pub async fn connect(path: &str) -> Result<Self, String> {
+        let url = format!("sqlite://{}?mode=rwc", path);
+        let pool = SqlitePool::connect(&url)
+            .await
+            .map_err(|e| format!("Failed to open store at {}: {}", path, e))?;
+
+        let store = Self { pool };
+        store.migrate().await?;
+        Ok(store)
+    }
+
+    async fn migrate(&self) -> Result<(), String> {
+        sqlx::query(
+            "CREATE TABLE IF NOT EXISTS windows (
+                id TEXT PRIMARY KEY,
+                data TEXT NOT NULL
+            )",
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to create windows table: {}", e))?;
+
+        sqlx::query(
+            "CREATE TABLE IF NOT EXISTS layouts (
+                name TEXT PRIMARY KEY,
+                windows_json TEXT NOT NULL,
+                saved_at REAL NOT NULL
+            )",
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to create layouts table: {}", e))?;
+
+        sqlx::query(
+            "CREATE TABLE IF NOT EXISTS workflow_runs (
+                id TEXT PRIMARY KEY,
+                data TEXT NOT NULL
+            )",
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to create workflow_runs table: {}", e))?;
+
+        Ok(())
+    }
+
+    /// Upserts an office window's persisted record.
+    pub async fn save_window(&self, window: &OfficeWindow) -> Result<(), String> {
+        let data = serde_json::to_string(window).map_err(|e| e.to_string())?;
+        sqlx::query("INSERT INTO windows (id, data) VALUES (?1, ?2) ON CONFLICT(id) DO UPDATE SET data = excluded.data")
+            .bind(&window.id)
+            .bind(data)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to save window {}: {}", window.id, e))?;
+        Ok(())
+    }
+
+    pub async fn remove_window(&self, window_id: &str) -> Result<(), String> {
+        sqlx::query("DELETE FROM windows WHERE id = ?1")
+            .bind(window_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to remove window {}: {}", window_id, e))?;
+        Ok(())
+    }
+
+    /// Loads every persisted office window, used to hydrate `WindowManager`'s in-memory map and
+    /// re-open the previously active offices on startup.
+    pub async fn load_windows(&self) -> Result<Vec<OfficeWindow>, String> {
+        let rows = sqlx::query("SELECT data FROM windows")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to load windows: {}", e))?;
+
+        rows.iter().map(Self::decode_window).collect()
+    }
+
+    fn decode_window(row: &SqliteRow) -> Result<OfficeWindow, String> {
+        let data: String = row.try_get("data").map_err(|e| e.to_string())?;
+        serde_json::from_str(&data).map_err(|e| format!("Failed to decode window: {}", e))
+    }
+
+    /// Snapshots the given windows under `name`, overwriting any prior layout with that name.
+    pub async fn save_layout(&self, name: &str, windows: &[OfficeWindow]) -> Result<(), String> {
+        let data = serde_json::to_string(windows).map_err(|e| e.to_string())?;
+        sqlx::query(
+            "INSERT INTO layouts (name, windows_json, saved_at) VALUES (?1, ?2, ?3)
+             ON CONFLICT(name) DO UPDATE SET windows_json = excluded.windows_json, saved_at = excluded.saved_at",
+        )
+        .bind(name)
+        .bind(data)
+        .bind(now())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| format!("Failed to save layout {}: {}", name, e))?;
+        Ok(())
+    }
+
+    /// Returns the windows snapshotted under `name`, for the caller to re-open.
+    pub async fn restore_layout(&self, name: &str) -> Result<Vec<OfficeWindow>, String> {
+        let row = sqlx::query("SELECT windows_json FROM layouts WHERE name = ?1")
+            .bind(name)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to load layout {}: {}", name, e))?
+            .ok_or_else(|| format!("No layout named {}", name))?;
+
+        let data: String = row.try_get("windows_json").map_err(|e| e.to_string())?;
+        serde_json::from_str(&data).map_err(|e| format!("Failed to decode layout {}: {}", name, e))
+    }
+
+    /// Records a new workflow run with every step `Pending`.
+    pub async fn start_workflow_run(&self, id: &str, name: &str, step_count: usize) -> Result<(), String> {
+        let run = WorkflowRun {
+            id: id.to_string(),
+            name: name.to_string(),
+            steps: vec![StepStatus::Pending; step_count],
+            started_at: now(),
+            finished_at: None,
+        };
+        self.put_workflow_run(&run).await
+    }
+
+    /// Updates the status of a single step within a workflow run.
+    pub async fn update_step_status(&self, id: &str, step_index: usize, status: StepStatus) -> Result<(), String> {
+        let mut run = self.get_workflow_run(id).await?;
+        if let Some(slot) = run.steps.get_mut(step_index) {
+            *slot = status;
+        }
+        if run.steps.iter().all(|s| matches!(s, StepStatus::Done)) {
+            run.finished_at = Some(now());
+        }
+        self.put_workflow_run(&run).await
+    }
+
+    pub async fn get_workflow_run(&self, id: &str) -> Result<WorkflowRun, String> {
+        let row = sqlx::query("SELECT data FROM workflow_runs WHERE id = ?1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to load workflow run {}: {}", id, e))?
+            .ok_or_else(|| format!("No workflow run with id {}", id))?;
+
+        let data: String = row.try_get("data").map_err(|e| e.to_string())?;
+        serde_json::from_str(&data).map_err(|e| format!("Failed to decode workflow run {}: {}", id, e))
+    }
+
+    async fn put_workflow_run(&self, run: &WorkflowRun) -> Result<(), String> {
+        let data = serde_json::to_string(run).map_err(|e| e.to_string())?;
+        sqlx::query("INSERT INTO workflow_runs (id, data) VALUES (?1, ?2) ON CONFLICT(id) DO UPDATE SET data = excluded.data")
+            .bind(&run.id)
+            .bind(data)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| format!("Failed to save workflow run {}: {}", run.id, e))?;
+        Ok(())
+    }
+}